@@ -0,0 +1,405 @@
+//! Airy functions of complex argument.
+//!
+//! As in D. E. Amos' algorithm 644, these come from the modified Bessel
+//! functions of orders `±1/3` and `±2/3` at `ζ = (2/3) z^(3/2)`, so for large
+//! `|z|` they are the Airy asymptotic expansions by way of the Hankel
+//! expansions of `I_ν` and `K_ν`.  Near the origin they are summed from their
+//! Maclaurin series instead.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::bessel::{bessel_i, bessel_k, Scaled};
+use crate::{constant, Complex};
+
+/// `Ai(0)` and `-Ai'(0)`, the coefficients of the two Maclaurin series.
+const AI_0: f64 = 0.3550280538878172;
+const NEG_AI_PRIME_0: f64 = 0.2588194037928068;
+
+/// Up to this magnitude, the Maclaurin series are used, which need this many
+/// terms.
+const SERIES_MAX: f64 = 1.0;
+const SERIES_TERMS: u32 = 10;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Airy {
+    Ai,
+    AiPrime,
+    Bi,
+    BiPrime,
+}
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the Airy function `Ai(self)`, the solution of `w'' = z w`
+    /// that decays along the positive real axis.
+    ///
+    /// Real arguments give real results.  The `f64` result is within a few
+    /// times `1e-15` of the function's size, measured over both parts
+    /// together, except that the relative error grows near the zeros and,
+    /// through the exponential factor `e^-ζ`, in proportion to `|ζ|`, where
+    /// `ζ = (2/3) self^(3/2)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let ai = Complex64::new(1.0, 1.0).airy_ai();
+    /// assert!((ai - Complex64::new(0.060458308371838146, -0.15188956587718141)).norm() < 1e-15);
+    ///
+    /// // the first zero
+    /// assert!(Complex64::new(-2.338107410459767, 0.0).airy_ai().norm() < 1e-15);
+    /// ```
+    pub fn airy_ai(self) -> Self {
+        real_on_axis(self, airy(self, Airy::Ai).unscaled())
+    }
+
+    /// Computes the derivative of the Airy function, `Ai'(self)`.
+    pub fn airy_ai_prime(self) -> Self {
+        real_on_axis(self, airy(self, Airy::AiPrime).unscaled())
+    }
+
+    /// Computes the Airy function of the second kind, `Bi(self)`, the
+    /// solution of `w'' = z w` that grows along the positive real axis.
+    ///
+    /// See [`airy_ai`](Complex::airy_ai) for the accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::FRAC_1_PI;
+    ///
+    /// // the Wronskian of Ai and Bi is 1/π everywhere
+    /// let z = Complex64::new(-1.5, 0.5);
+    /// let w = z.airy_ai() * z.airy_bi_prime() - z.airy_ai_prime() * z.airy_bi();
+    /// assert!((w - FRAC_1_PI).norm() < 1e-14);
+    /// ```
+    pub fn airy_bi(self) -> Self {
+        real_on_axis(self, airy(self, Airy::Bi).unscaled())
+    }
+
+    /// Computes the derivative of the Airy function of the second kind,
+    /// `Bi'(self)`.
+    pub fn airy_bi_prime(self) -> Self {
+        real_on_axis(self, airy(self, Airy::BiPrime).unscaled())
+    }
+
+    /// Computes the exponentially scaled Airy function, `e^ζ Ai(self)` with
+    /// `ζ = (2/3) self^(3/2)`, which doesn't underflow in `|arg z| < π/3`.
+    ///
+    /// Unlike `Ai` itself, this is only real on the positive real axis.
+    pub fn airy_ai_scaled(self) -> Self {
+        let w = airy(self, Airy::Ai).rescaled(-zeta(self));
+        real_on_positive_axis(self, w)
+    }
+
+    /// Computes the exponentially scaled derivative `e^ζ Ai'(self)`, with
+    /// `ζ = (2/3) self^(3/2)`.
+    pub fn airy_ai_prime_scaled(self) -> Self {
+        let w = airy(self, Airy::AiPrime).rescaled(-zeta(self));
+        real_on_positive_axis(self, w)
+    }
+
+    /// Computes the exponentially scaled Airy function of the second kind,
+    /// `e^(-|Re ζ|) Bi(self)` with `ζ = (2/3) self^(3/2)`, which doesn't
+    /// overflow in `|arg z| < π/3`.
+    pub fn airy_bi_scaled(self) -> Self {
+        let scale = Self::from(zeta(self).re.abs());
+        real_on_axis(self, airy(self, Airy::Bi).rescaled(scale))
+    }
+
+    /// Computes the exponentially scaled derivative `e^(-|Re ζ|) Bi'(self)`,
+    /// with `ζ = (2/3) self^(3/2)`.
+    pub fn airy_bi_prime_scaled(self) -> Self {
+        let scale = Self::from(zeta(self).re.abs());
+        real_on_axis(self, airy(self, Airy::BiPrime).rescaled(scale))
+    }
+}
+
+/// The Airy functions and their derivatives are real on the real axis.
+fn real_on_axis<T: Float>(z: Complex<T>, w: Complex<T>) -> Complex<T> {
+    if z.im.is_zero() {
+        Complex::new(w.re, z.im)
+    } else {
+        w
+    }
+}
+
+/// The scaled `Ai` and `Ai'` are only real on the positive real axis, since
+/// `ζ` is imaginary on the negative one.
+fn real_on_positive_axis<T: Float>(z: Complex<T>, w: Complex<T>) -> Complex<T> {
+    if z.re > T::zero() {
+        real_on_axis(z, w)
+    } else {
+        w
+    }
+}
+
+/// `ζ = (2/3) z^(3/2)`.
+fn zeta<T: Float>(z: Complex<T>) -> Complex<T> {
+    let two = T::one() + T::one();
+    (z * z.sqrt()).scale(two / (two + T::one()))
+}
+
+/// Computes `f(z)` as a scaled value, from
+///
+/// - `Ai(z) = √z/(π√3) K_(1/3)(ζ) = √z/3 (I_(-1/3)(ζ) - I_(1/3)(ζ))`
+/// - `Ai'(z) = -z/(π√3) K_(2/3)(ζ) = z/3 (I_(2/3)(ζ) - I_(-2/3)(ζ))`
+/// - `Bi(z) = √z/√3 (I_(-1/3)(ζ) + I_(1/3)(ζ))`
+/// - `Bi'(z) = z/√3 (I_(-2/3)(ζ) + I_(2/3)(ζ))`
+///
+/// using `K_ν` for `Ai` where it decays, in `|arg z| ≤ π/3`.
+fn airy<T: Float + FloatConst>(z: Complex<T>, f: Airy) -> Scaled<T> {
+    if z.norm() <= constant(SERIES_MAX) {
+        return Scaled::new(airy_series(z, f), Complex::zero());
+    }
+    let one = T::one();
+    let three = one + one + one;
+    let sqrt_3 = three.sqrt();
+    let zeta = zeta(z);
+    let derivative = f == Airy::AiPrime || f == Airy::BiPrime;
+    let (nu, g) = if derivative {
+        ((one + one) / three, z)
+    } else {
+        (three.recip(), z.sqrt())
+    };
+
+    let sector = z.re >= T::zero() && z.im.abs() <= sqrt_3 * z.re;
+    if sector && f == Airy::Ai {
+        return bessel_k(nu, zeta).mul(g.scale(T::FRAC_1_PI() / sqrt_3));
+    }
+    if sector && f == Airy::AiPrime {
+        return bessel_k(nu, zeta).mul(g.scale(-T::FRAC_1_PI() / sqrt_3));
+    }
+
+    // I_(±ν)(ζ), which outside the sector is continued from -ζ in the right
+    // half-plane with I_ν(w e^(±iπ)) = e^(±iπν) I_ν(w)
+    let (plus, minus) = if sector {
+        (bessel_i(nu, zeta), bessel_i(-nu, zeta))
+    } else {
+        let m = if z.im.is_sign_negative() { -one } else { one };
+        let w = -zeta;
+        (
+            bessel_i(nu, w).mul(Complex::cis_pi(m * nu)),
+            bessel_i(-nu, w).mul(Complex::cis_pi(-m * nu)),
+        )
+    };
+    let neg = -Complex::<T>::one();
+    match f {
+        Airy::Ai => minus.add(plus.mul(neg)).mul(g.unscale(three)),
+        Airy::AiPrime => plus.add(minus.mul(neg)).mul(g.unscale(three)),
+        Airy::Bi | Airy::BiPrime => minus.add(plus).mul(g.unscale(sqrt_3)),
+    }
+}
+
+/// Computes `f(z)` from the Maclaurin series `Ai(z) = Ai(0) f(z) + Ai'(0) g(z)`
+/// and `Bi(z) = √3 (Ai(0) f(z) - Ai'(0) g(z))`, with
+///
+/// - `f(z) = 1 + z³/3! + 1·4 z⁶/6! + 1·4·7 z⁹/9! + ...`
+/// - `g(z) = z + 2 z⁴/4! + 2·5 z⁷/7! + 2·5·8 z¹⁰/10! + ...`
+fn airy_series<T: Float + FloatConst>(z: Complex<T>, f: Airy) -> Complex<T> {
+    let z3 = z * z * z;
+    let (a, b) = if f == Airy::AiPrime || f == Airy::BiPrime {
+        let half = (T::one() + T::one()).recip();
+        (
+            maclaurin((z * z).scale(half), z3, 3, 5),
+            maclaurin(Complex::one(), z3, 1, 3),
+        )
+    } else {
+        (maclaurin(Complex::one(), z3, 2, 3), maclaurin(z, z3, 3, 4))
+    };
+    let a = a.scale(constant(AI_0));
+    let b = b.scale(constant(NEG_AI_PRIME_0));
+    match f {
+        Airy::Ai | Airy::AiPrime => a - b,
+        Airy::Bi | Airy::BiPrime => (a + b).scale((T::one() + T::one() + T::one()).sqrt()),
+    }
+}
+
+/// Returns `Σ t_k`, with `t_0 = first` and `t_(k+1) = t_k z³ / ((3k + a)(3k + b))`.
+fn maclaurin<T: Float>(first: Complex<T>, z3: Complex<T>, a: u32, b: u32) -> Complex<T> {
+    let mut term = first;
+    let mut sum = first;
+    for k in 0..SERIES_TERMS {
+        let d = T::from((3 * k + a) * (3 * k + b)).unwrap();
+        term = (term * z3).unscale(d);
+        sum = sum + term;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::Complex64;
+    use std::f64;
+
+    // z, f(z) with references from mpmath
+    type Case = ((f64, f64), (f64, f64));
+    type Function = fn(Complex64) -> Complex64;
+
+    fn check(f: Function, cases: &[Case]) {
+        for &((x, y), (re, im)) in cases {
+            let z = Complex64::new(x, y);
+            let expected = Complex64::new(re, im);
+            let w = f(z);
+            assert!(
+                close_to_tol(w, expected, 1e-14 * expected.norm()),
+                "{:?}: {:?} != {:?}",
+                z,
+                w,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_airy_ai() {
+        let ai: &[Case] = &[
+            ((0.5, 0.0), (0.23169360648083348, 0.0)),
+            ((1.0, 1.0), (0.060458308371838146, -0.15188956587718141)),
+            ((3.0, -2.0), (-0.00967720105861024, -0.005524689111732706)),
+            ((-4.0, 0.0), (-0.07026553294928951, 0.0)),
+            ((-2.0, 3.0), (19.47375324426692, -1.982011735065675)),
+            ((-6.0, -1.0), (-1.8665305812449398, -0.9559654835184781)),
+            ((0.0, 8.0), (435.62314214160256, 7206.34474890413)),
+            (
+                (12.0, 0.5),
+                (-2.4223414693430414e-14, -1.3974098701294454e-13),
+            ),
+        ];
+        check(Complex64::airy_ai, ai);
+        let ai_prime: &[Case] = &[
+            ((0.5, 0.0), (-0.2249105326646839, 0.0)),
+            ((1.0, 1.0), (-0.1306279534996475, 0.16306759644932392)),
+            ((3.0, -2.0), (0.020990085245160245, 0.005347465695574646)),
+            ((-4.0, 0.0), (-0.7906285753685813, 0.0)),
+            ((-2.0, 3.0), (-19.77811614950742, -29.6604044120752)),
+            ((-6.0, -1.0), (2.6829944789224482, -4.355480324086082)),
+            ((0.0, 8.0), (13311.58997252232, -15274.898369529776)),
+            ((12.0, 0.5), (7.44611219327258e-14, 4.887737803938671e-13)),
+        ];
+        check(Complex64::airy_ai_prime, ai_prime);
+    }
+
+    #[test]
+    fn test_airy_bi() {
+        let bi: &[Case] = &[
+            ((0.5, 0.0), (0.8542770431031554, 0.0)),
+            ((1.0, 1.0), (0.7166580733827684, 0.6198892904008447)),
+            ((3.0, -2.0), (-7.337352267867407, 1.6615230385785593)),
+            ((-4.0, 0.0), (0.3922347057069993, 0.0)),
+            ((-2.0, 3.0), (1.9844101512117995, 19.470187176247997)),
+            ((-6.0, -1.0), (-0.972412952510648, 1.840408945792749)),
+            ((0.0, 8.0), (-7206.344754071034, 435.6231363062428)),
+            ((12.0, 0.5), (-48652067274.85263, 320162243852.37805)),
+        ];
+        check(Complex64::airy_bi, bi);
+        let bi_prime: &[Case] = &[
+            ((0.5, 0.0), (0.5445725641405923, 0.0)),
+            ((1.0, 1.0), (0.07566284417496599, 0.7837009987854553)),
+            ((3.0, -2.0), (-11.918089566947769, 7.254625440784201)),
+            ((-4.0, 0.0), (-0.1166705674383409, 0.0)),
+            ((-2.0, 3.0), (29.668798849518367, -19.777292990871622)),
+            ((-6.0, -1.0), (-4.423899125695126, -2.648784705171195)),
+            ((0.0, 8.0), (15274.898371042622, 13311.58995035621)),
+            ((12.0, 0.5), (-190933928219.77094, 1098999083686.9465)),
+        ];
+        check(Complex64::airy_bi_prime, bi_prime);
+    }
+
+    #[test]
+    fn test_airy_zeros() {
+        // the first two zeros of Ai, Bi, Ai' and Bi'
+        let zeros: [(Function, [f64; 2]); 4] = [
+            (Complex64::airy_ai, [-2.338107410459767, -4.08794944413097]),
+            (Complex64::airy_bi, [-1.173713222709128, -3.271093302836353]),
+            (
+                Complex64::airy_ai_prime,
+                [-1.018792971647471, -3.2481975821798366],
+            ),
+            (
+                Complex64::airy_bi_prime,
+                [-2.294439682614123, -4.073155089071828],
+            ),
+        ];
+        for &(f, xs) in &zeros {
+            for &x in &xs {
+                let w = f(Complex64::new(x, 0.0));
+                assert_eq!(w.im, 0.0);
+                assert!(w.re.abs() < 4e-15, "{}: {}", x, w.re);
+            }
+        }
+        assert_eq!(Complex64::new(0.0, 0.0).airy_ai().re, 0.3550280538878172);
+        assert_eq!(
+            Complex64::new(0.0, 0.0).airy_ai_prime().re,
+            -0.2588194037928068
+        );
+    }
+
+    #[test]
+    fn test_airy_wronskian() {
+        // Ai Bi' - Ai' Bi = 1/π, and for the rotated solutions
+        // ω Ai(z) Ai'(ωz) - Ai'(z) Ai(ωz) = e^(-iπ/6) / 2π with ω = e^(2πi/3)
+        let omega = Complex64::cis_pi(2.0 / 3.0);
+        let c = Complex64::cis_pi(-1.0 / 6.0) * f64::consts::FRAC_1_PI / 2.0;
+        for &(x, y) in &[
+            (0.3, 0.2),
+            (2.0, 1.0),
+            (-3.0, 2.0),
+            (-5.0, -0.5),
+            (1.0, -6.0),
+        ] {
+            let z = Complex64::new(x, y);
+            let (ai, ai_prime) = (z.airy_ai(), z.airy_ai_prime());
+            let w = ai * z.airy_bi_prime() - ai_prime * z.airy_bi();
+            let scale = (ai * z.airy_bi_prime()).norm().max(1.0);
+            assert!(close_to_tol(
+                w,
+                f64::consts::FRAC_1_PI.into(),
+                1e-14 * scale
+            ));
+
+            let wz = omega * z;
+            let w = omega * ai * wz.airy_ai_prime() - ai_prime * wz.airy_ai();
+            let scale = (ai * wz.airy_ai_prime()).norm().max(1.0);
+            assert!(close_to_tol(w, c, 1e-14 * scale));
+        }
+    }
+
+    #[test]
+    fn test_airy_scaled() {
+        for &(x, y) in &[
+            (0.5, 0.5),
+            (3.0, -2.0),
+            (-4.0, 0.0),
+            (-2.0, 3.0),
+            (5.0, 0.0),
+        ] {
+            let z = Complex64::new(x, y);
+            let zeta = z * z.sqrt() * (2.0 / 3.0);
+            let pairs = [
+                (z.airy_ai_scaled(), z.airy_ai() * zeta.exp()),
+                (z.airy_ai_prime_scaled(), z.airy_ai_prime() * zeta.exp()),
+                (z.airy_bi_scaled(), z.airy_bi() * (-zeta.re.abs()).exp()),
+                (
+                    z.airy_bi_prime_scaled(),
+                    z.airy_bi_prime() * (-zeta.re.abs()).exp(),
+                ),
+            ];
+            for &(scaled, expected) in &pairs {
+                assert!(close_to_tol(scaled, expected, 1e-14 * expected.norm()));
+            }
+        }
+
+        // Ai(z) ~ e^-ζ / (2√π z^(1/4)), far past where it underflows
+        let z = Complex64::new(1e4, 0.0);
+        assert_eq!(z.airy_ai(), Complex64::new(0.0, 0.0));
+        let expected = 1.0 / (2.0 * f64::consts::PI.sqrt() * 10.0);
+        let ai = z.airy_ai_scaled();
+        assert_eq!(ai.im, 0.0);
+        assert!((ai.re - expected).abs() < 1e-6 * expected);
+        assert!(z.airy_bi().re.is_infinite());
+        assert!((z.airy_bi_scaled().re - 2.0 * expected).abs() < 1e-6 * expected);
+    }
+}
@@ -0,0 +1,237 @@
+//! Non-principal branches of multi-valued functions.
+
+use core::iter::FusedIterator;
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::Complex;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes branch `k` of the natural logarithm of `self`.
+    ///
+    /// This is `ln(self) + 2πik`, so branch 0 is the principal value
+    /// [`ln`](Complex::ln), and `ln_branch(k).exp()` is `self` for every `k`.
+    /// Successive branches are the successive sheets of the logarithm's Riemann
+    /// surface, crossing from branch `k` to `k + 1` when going counterclockwise
+    /// across the cut on the negative real axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Complex64::new(-1.0, 0.0);
+    /// assert_eq!(z.ln_branch(0), Complex64::new(0.0, PI));
+    /// assert_eq!(z.ln_branch(1), Complex64::new(0.0, 3.0 * PI));
+    /// assert_eq!(z.ln_branch(-1), Complex64::new(0.0, -PI));
+    /// ```
+    #[inline]
+    pub fn ln_branch(self, k: i32) -> Self {
+        let Complex { re, im } = self.ln();
+        Self::new(re, im + turns(T::from(k).unwrap()))
+    }
+
+    /// Raises `self` to a floating point power on branch `k`.
+    ///
+    /// This is `exp(exp * ln_branch(k))`, so branch 0 is the principal value
+    /// [`powf`](Complex::powf).  For a rational `exp = p/q` in lowest terms,
+    /// the branches repeat with period `q`.
+    #[inline]
+    pub fn powf_branch(self, exp: T, k: i32) -> Self {
+        if exp.is_zero() {
+            return Self::one();
+        }
+        let (r, theta) = self.to_polar();
+        let theta = theta + turns(T::from(k).unwrap());
+        Self::from_polar(r.powf(exp), theta * exp)
+    }
+
+    /// Raises `self` to a complex power on branch `k`.
+    ///
+    /// This is `exp(exp * ln_branch(k))`, so branch 0 is the principal value
+    /// [`powc`](Complex::powc).
+    #[inline]
+    pub fn powc_branch(self, exp: Self, k: i32) -> Self {
+        if exp.is_zero() {
+            return Self::one();
+        }
+        (exp * self.ln_branch(k)).exp()
+    }
+
+    /// Returns an iterator over all `n` of the `n`th roots of `self`.
+    ///
+    /// Root `k`, for `k` in `0..n`, is `|self|^(1/n) e^(i(arg(self) + 2πk)/n)`,
+    /// so the first root is the principal value and the rest follow it
+    /// counterclockwise around the origin, each a further `2π/n` apart.  For a
+    /// zero `self`, all `n` roots are zero.
+    ///
+    /// The angles are computed with [`cis_pi`](Complex::cis_pi), so for
+    /// example the 4th roots of unity are exactly `1`, `i`, `-1` and `-i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let roots: Vec<Complex64> = Complex64::new(-8.0, 0.0).nth_roots(3).collect();
+    /// assert_eq!(roots.len(), 3);
+    /// assert!((roots[0] - Complex64::new(1.0, 3f64.sqrt())).norm() < 1e-15);
+    /// assert!((roots[1] - Complex64::new(-2.0, 0.0)).norm() < 1e-15);
+    /// assert!((roots[2] - Complex64::new(1.0, -(3f64.sqrt()))).norm() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn nth_roots(self, n: u32) -> NthRoots<T> {
+        assert!(n > 0, "there are no 0th roots");
+        let (r, theta) = self.to_polar();
+        NthRoots {
+            r: r.powf(T::from(n).unwrap().recip()),
+            half_turns: theta / T::PI(),
+            n,
+            k: 0,
+        }
+    }
+}
+
+/// Returns the angle of `k` full turns, `2πk`.
+#[inline]
+fn turns<T: Float + FloatConst>(k: T) -> T {
+    (T::PI() + T::PI()) * k
+}
+
+/// An iterator over the `n`th roots of a complex number.
+///
+/// This `struct` is created by [`Complex::nth_roots`].
+#[derive(Clone, Debug)]
+pub struct NthRoots<T> {
+    r: T,
+    // arg(z) / π, which is exact on the axes
+    half_turns: T,
+    n: u32,
+    k: u32,
+}
+
+impl<T: Float + FloatConst> Iterator for NthRoots<T> {
+    type Item = Complex<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Complex<T>> {
+        if self.k == self.n {
+            return None;
+        }
+        let k = T::from(self.k).unwrap();
+        let n = T::from(self.n).unwrap();
+        self.k += 1;
+        // The angle in half-turns, so roots of real or imaginary numbers land
+        // exactly on the axes where they should.
+        let two = T::one() + T::one();
+        Some(Complex::cis_pi((self.half_turns + two * k) / n).scale(self.r))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.n - self.k) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T: Float + FloatConst> ExactSizeIterator for NthRoots<T> {}
+
+impl<T: Float + FloatConst> FusedIterator for NthRoots<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close;
+    use crate::test::{_05_05i, _0_0i, _0_1i, _1_0i, _1_1i, _neg1_1i, all_consts};
+    use crate::{Complex, Complex64};
+    use std::f64::consts::{PI, TAU};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_ln_branch() {
+        for &c in all_consts.iter() {
+            assert_eq!(c.ln_branch(0), c.ln());
+            for k in -3..=3 {
+                let w = c.ln_branch(k);
+                assert_eq!(w.re, c.ln().re);
+                assert!((w.im - c.arg() - TAU * f64::from(k)).abs() < 1e-14);
+                if c != _0_0i {
+                    assert!(close(w.exp(), c));
+                }
+            }
+        }
+        // The sign of a zero imaginary part still selects the side of the cut.
+        let z = Complex::new(-1.0, -0.0);
+        assert_eq!(z.ln_branch(1), Complex::new(0.0, PI));
+    }
+
+    #[test]
+    fn test_powf_branch() {
+        for &c in all_consts.iter() {
+            assert!(close(c.powf_branch(0.5, 0), c.powf(0.5)));
+            // Branches of the square root alternate in sign.
+            assert!(close(c.powf_branch(0.5, 1), -c.powf(0.5)));
+            assert!(close(c.powf_branch(0.5, 2), c.powf(0.5)));
+            // Integer powers are single-valued.
+            assert!(close(c.powf_branch(3.0, -2), c.powi(3)));
+            assert_eq!(c.powf_branch(0.0, 5), _1_0i);
+        }
+    }
+
+    #[test]
+    fn test_powc_branch() {
+        let exps = [_05_05i, _0_1i, Complex::new(-1.5, 0.0)];
+        for &c in [_1_0i, _1_1i, _neg1_1i, _05_05i].iter() {
+            for &e in exps.iter() {
+                assert!(close(c.powc_branch(e, 0), c.powc(e)));
+                let w = c.powc_branch(e, 2);
+                let expected = (e * (c.ln() + Complex::new(0.0, 2.0 * TAU))).exp();
+                assert!(close(w, expected));
+            }
+            assert_eq!(c.powc_branch(_0_0i, 3), _1_0i);
+        }
+        // i^i takes the real values e^(-π/2 - 2πk).
+        for k in -2..=2 {
+            let w = _0_1i.powc_branch(_0_1i, k);
+            let expected = (-PI / 2.0 - TAU * f64::from(k)).exp();
+            assert!((w.re - expected).abs() < 1e-12 * expected);
+            assert!(w.im.abs() < 1e-12 * expected);
+        }
+    }
+
+    #[test]
+    fn test_nth_roots() {
+        for &c in all_consts.iter() {
+            for n in 1..=6 {
+                let roots: Vec<Complex64> = c.nth_roots(n).collect();
+                assert_eq!(roots.len(), n as usize);
+                assert!(close(roots[0], c.powf(1.0 / f64::from(n))));
+                for (k, &w) in roots.iter().enumerate() {
+                    assert!(close(w.powi(n as i32), c));
+                    assert!(close(w, c.powf_branch(1.0 / f64::from(n), k as i32)));
+                }
+            }
+        }
+        let roots: Vec<Complex64> = _0_0i.nth_roots(4).collect();
+        assert_eq!(roots, [_0_0i; 4]);
+
+        let mut iter = _1_0i.nth_roots(4);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(1), Some(_0_1i));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(-_1_0i));
+        assert_eq!(iter.next(), Some(-_0_1i));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "0th roots")]
+    fn test_nth_roots_zero() {
+        _1_0i.nth_roots(0);
+    }
+}
@@ -0,0 +1,243 @@
+//! Compensated sums and dot products, after T. Ogita, S. M. Rump and S. Oishi,
+//! "Accurate Sum and Dot Product", SIAM J. Sci. Comput. 26(6), 2005.
+
+use core::borrow::Borrow;
+
+use num_traits::Float;
+
+use crate::Complex;
+
+/// Error-free sum: returns `(s, e)` with `s = fl(a + b)` and `a + b = s + e` exactly.
+#[inline]
+pub(crate) fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let bb = s - a;
+    let e = (a - (s - bb)) + (b - bb);
+    (s, e)
+}
+
+/// Error-free product: returns `(p, e)` with `p = fl(a * b)` and `a * b = p + e` exactly,
+/// as long as `T::mul_add` is fused and nothing underflows.
+#[inline]
+pub(crate) fn two_prod<T: Float>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Sums `terms` in roughly twice the working precision.
+#[inline]
+pub(crate) fn sum2<T: Float>(terms: &[T]) -> T {
+    let mut sum = Sum2::new();
+    for &t in terms {
+        sum.add(t);
+    }
+    sum.value()
+}
+
+/// A running sum of real terms, with the rounding error of each addition
+/// collected separately (the `Sum2` algorithm).
+#[derive(Clone, Copy)]
+struct Sum2<T> {
+    sum: T,
+    comp: T,
+}
+
+impl<T: Float> Sum2<T> {
+    fn new() -> Self {
+        Sum2 {
+            sum: T::zero(),
+            comp: T::zero(),
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, x: T) {
+        let (s, e) = two_sum(self.sum, x);
+        self.sum = s;
+        self.comp = self.comp + e;
+    }
+
+    /// Adds the exact product `a * b`, as in the `Dot2` algorithm.
+    #[inline]
+    fn add_prod(&mut self, a: T, b: T) {
+        let (p, e) = two_prod(a, b);
+        self.add(p);
+        self.comp = self.comp + e;
+    }
+
+    fn value(self) -> T {
+        if self.sum.is_finite() {
+            self.sum + self.comp
+        } else {
+            // The error terms are NaN once anything overflows,
+            // but the plain sum still has the right special value.
+            self.sum
+        }
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// Returns the sum of `iter` with compensated summation.
+    ///
+    /// The result is as accurate as if it were summed in twice the working
+    /// precision and then rounded, so the error no longer grows with the
+    /// number of terms the way it does for the plain `Sum` implementation.
+    /// This accepts owned or borrowed values, so it works on slices as well as
+    /// iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// let v = [
+    ///     Complex64::new(1.0, 1e100),
+    ///     Complex64::new(1e100, 1.0),
+    ///     Complex64::new(-1e100, -1e100),
+    /// ];
+    ///
+    /// // The plain sum loses the small parts completely.
+    /// assert_eq!(v.iter().sum::<Complex64>(), Complex64::new(0.0, 0.0));
+    ///
+    /// assert_eq!(Complex64::sum_compensated(&v), Complex64::new(1.0, 1.0));
+    /// ```
+    pub fn sum_compensated<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut re = Sum2::new();
+        let mut im = Sum2::new();
+        for z in iter {
+            let z = z.borrow();
+            re.add(z.re);
+            im.add(z.im);
+        }
+        Self::new(re.value(), im.value())
+    }
+
+    /// Returns the dot product `Σ a[k] * b[k]` with compensated summation.
+    ///
+    /// Each product is computed exactly with fused multiply-add, so the result
+    /// is as accurate as if it were computed in twice the working precision
+    /// and then rounded, provided `T::mul_add` is fused and nothing overflows
+    /// or underflows.  The sum stops at the end of the shorter input.
+    ///
+    /// See also [`dotc_compensated`](Complex::dotc_compensated), which
+    /// conjugates `a`.
+    pub fn dot_compensated<I, J>(a: I, b: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+        J: IntoIterator,
+        J::Item: Borrow<Self>,
+    {
+        let mut re = Sum2::new();
+        let mut im = Sum2::new();
+        for (x, y) in a.into_iter().zip(b) {
+            let (x, y) = (x.borrow(), y.borrow());
+            re.add_prod(x.re, y.re);
+            re.add_prod(-x.im, y.im);
+            im.add_prod(x.re, y.im);
+            im.add_prod(x.im, y.re);
+        }
+        Self::new(re.value(), im.value())
+    }
+
+    /// Returns the conjugated dot product `Σ conj(a[k]) * b[k]` with
+    /// compensated summation.
+    ///
+    /// This is the inner product of `a` and `b`, like BLAS `zdotc`, and is
+    /// otherwise the same as [`dot_compensated`](Complex::dot_compensated).
+    pub fn dotc_compensated<I, J>(a: I, b: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+        J: IntoIterator,
+        J::Item: Borrow<Self>,
+    {
+        let mut re = Sum2::new();
+        let mut im = Sum2::new();
+        for (x, y) in a.into_iter().zip(b) {
+            let (x, y) = (x.borrow(), y.borrow());
+            re.add_prod(x.re, y.re);
+            re.add_prod(x.im, y.im);
+            im.add_prod(x.re, y.im);
+            im.add_prod(-x.im, y.re);
+        }
+        Self::new(re.value(), im.value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Complex, Complex64};
+    use std::f64;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_sum_compensated() {
+        let v = [
+            Complex::new(1.0, 1e100),
+            Complex::new(1e100, 1.0),
+            Complex::new(1.0, -1e100),
+            Complex::new(-1e100, 1.0),
+        ];
+        assert_eq!(v.iter().sum::<Complex64>(), Complex::new(0.0, 1.0));
+        assert_eq!(Complex::sum_compensated(&v[..]), Complex::new(2.0, 2.0));
+        assert_eq!(Complex::sum_compensated(v.to_vec()), Complex::new(2.0, 2.0));
+
+        // 0.1 isn't representable, and the plain sum drifts away from n * 0.1.
+        let n = 1_000_000;
+        let z = Complex::new(0.1, -0.1);
+        let sum = Complex::sum_compensated(core::iter::repeat(z).take(n));
+        assert_eq!(sum, Complex::new(100000.0, -100000.0));
+        assert!(core::iter::repeat(z).take(n).sum::<Complex64>() != sum);
+
+        let empty: [Complex64; 0] = [];
+        assert_eq!(Complex::sum_compensated(&empty[..]), Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_sum_compensated_special() {
+        let inf = f64::INFINITY;
+        let v = [Complex::new(inf, 1.0), Complex::new(1.0, f64::NAN)];
+        let sum = Complex::sum_compensated(v);
+        assert_eq!(sum.re, inf);
+        assert!(sum.im.is_nan());
+    }
+
+    #[test]
+    fn test_dot_compensated() {
+        let e = 2f64.powi(-30);
+        // (1 + e)(1 - e) - 1 = -e², which the plain dot product rounds to zero.
+        let a = [Complex::new(1.0 + e, 0.0), Complex::new(0.0, 1.0)];
+        let b = [Complex::new(1.0 - e, 0.0), Complex::new(0.0, 1.0)];
+        let plain: Complex64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        assert_eq!(plain, Complex::new(0.0, 0.0));
+        assert_eq!(Complex::dot_compensated(&a, &b), Complex::new(-e * e, 0.0));
+
+        let a = [Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+        let b = [Complex::new(5.0, -6.0), Complex::new(-7.0, 8.0)];
+        let plain: Complex64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        assert_eq!(Complex::dot_compensated(&a, &b), plain);
+
+        // Extra terms in the longer input are ignored.
+        assert_eq!(Complex::dot_compensated(&a, &b[..1]), a[0] * b[0]);
+    }
+
+    #[test]
+    fn test_dotc_compensated() {
+        let a = [Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+        let b = [Complex::new(5.0, -6.0), Complex::new(-7.0, 8.0)];
+        let plain: Complex64 = a.iter().zip(&b).map(|(x, y)| x.conj() * y).sum();
+        assert_eq!(Complex::dotc_compensated(&a, &b), plain);
+
+        // The inner product of a vector with itself is real.
+        let v: Vec<Complex64> = (1..100)
+            .map(|k| Complex::cis(f64::from(k)).scale(0.1))
+            .collect();
+        let norm_sqr = Complex::dotc_compensated(&v, &v);
+        assert_eq!(norm_sqr.im, 0.0);
+        assert!((norm_sqr.re - 0.99).abs() < 1e-15);
+    }
+}
@@ -5,6 +5,7 @@ use core::ops::Neg;
 
 use num_traits::{Float, FloatConst, Num, NumCast};
 
+use crate::gamma::{gamma_is_negative, is_pole};
 use crate::Complex;
 
 mod private {
@@ -61,6 +62,9 @@ pub trait ComplexFloat: Num + NumCast + Copy + Neg<Output = Self> + private::Sea
     /// Returns `e^(self)`, (the exponential function).
     fn exp(self) -> Self;
 
+    /// Returns `e^(self) - 1`, accurately even when `self` is close to zero.
+    fn exp_m1(self) -> Self;
+
     /// Returns `2^(self)`.
     fn exp2(self) -> Self;
 
@@ -70,6 +74,9 @@ pub trait ComplexFloat: Num + NumCast + Copy + Neg<Output = Self> + private::Sea
     /// Returns the natural logarithm of the number.
     fn ln(self) -> Self;
 
+    /// Returns `ln(1 + self)`, accurately even when `self` is close to zero.
+    fn ln_1p(self) -> Self;
+
     /// Returns the logarithm of the number with respect to an arbitrary base.
     fn log(self, base: Self::Real) -> Self;
 
@@ -123,6 +130,43 @@ pub trait ComplexFloat: Num + NumCast + Copy + Neg<Output = Self> + private::Sea
     /// Inverse hyperbolic tangent function.
     fn atanh(self) -> Self;
 
+    /// Returns the gamma function, `Γ(self)`.
+    fn gamma(self) -> Self;
+
+    /// Returns the log-gamma function, `ln Γ(self)`.
+    ///
+    /// For complex numbers this is the principal branch, which is analytic
+    /// off the negative real axis.  For real numbers it is NaN where `Γ` is
+    /// negative, just as `ln` is NaN for negative numbers.
+    fn ln_gamma(self) -> Self;
+
+    /// Returns the digamma function, `Γ'(self) / Γ(self)`.
+    fn digamma(self) -> Self;
+
+    /// Returns the error function, `erf(self)`.
+    fn erf(self) -> Self;
+
+    /// Returns the complementary error function, `erfc(self) = 1 - erf(self)`.
+    fn erfc(self) -> Self;
+
+    /// Returns the scaled complementary error function, `exp(self²) erfc(self)`.
+    fn erfcx(self) -> Self;
+
+    /// Returns the imaginary error function, `erfi(self) = -i erf(i self)`.
+    fn erfi(self) -> Self;
+
+    /// Returns Dawson's integral, `exp(-self²) ∫₀^self exp(t²) dt`.
+    fn dawson(self) -> Self;
+
+    /// Returns the Riemann zeta function, `ζ(self)`.
+    fn zeta(self) -> Self;
+
+    /// Returns the Hurwitz zeta function, `ζ(self, a) = Σ (n + a)^-self`.
+    ///
+    /// For real numbers it is NaN where the terms with negative `n + a` make
+    /// the sum complex, that is for negative `a` and non-integer `self`.
+    fn hurwitz_zeta(self, a: Self::Real) -> Self;
+
     /// Returns the real part of the number.
     fn re(self) -> Self::Real;
 
@@ -206,6 +250,55 @@ where
         base.powf(self)
     }
 
+    fn gamma(self) -> Self {
+        Complex::new(self, T::zero()).gamma().re
+    }
+
+    fn ln_gamma(self) -> Self {
+        let z = Complex::new(self, T::zero());
+        if !is_pole(z) && gamma_is_negative(self) {
+            T::nan()
+        } else {
+            z.ln_gamma().re
+        }
+    }
+
+    fn digamma(self) -> Self {
+        Complex::new(self, T::zero()).digamma().re
+    }
+
+    fn erf(self) -> Self {
+        Complex::new(self, T::zero()).erf().re
+    }
+
+    fn erfc(self) -> Self {
+        Complex::new(self, T::zero()).erfc().re
+    }
+
+    fn erfcx(self) -> Self {
+        Complex::new(self, T::zero()).erfcx().re
+    }
+
+    fn erfi(self) -> Self {
+        Complex::new(self, T::zero()).erfi().re
+    }
+
+    fn dawson(self) -> Self {
+        Complex::new(self, T::zero()).dawson().re
+    }
+
+    fn zeta(self) -> Self {
+        Complex::new(self, T::zero()).zeta().re
+    }
+
+    fn hurwitz_zeta(self, a: Self::Real) -> Self {
+        if a < T::zero() && self != self.floor() {
+            T::nan()
+        } else {
+            Complex::new(self, T::zero()).hurwitz_zeta(a).re
+        }
+    }
+
     forward! {
         Float::is_normal(self) -> bool;
         Float::is_infinite(self) -> bool;
@@ -217,8 +310,10 @@ where
         Float::sqrt(self) -> Self;
         Float::cbrt(self) -> Self;
         Float::exp(self) -> Self;
+        Float::exp_m1(self) -> Self;
         Float::exp2(self) -> Self;
         Float::ln(self) -> Self;
+        Float::ln_1p(self) -> Self;
         Float::log(self, base: Self) -> Self;
         Float::log2(self) -> Self;
         Float::log10(self) -> Self;
@@ -270,7 +365,7 @@ impl<T: Float + FloatConst> ComplexFloat for Complex<T> {
     }
 
     fn is_infinite(self) -> bool {
-        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+        self.re.is_infinite() || self.im.is_infinite()
     }
 
     fn is_finite(self) -> bool {
@@ -292,8 +387,10 @@ impl<T: Float + FloatConst> ComplexFloat for Complex<T> {
         Complex::sqrt(self) -> Self;
         Complex::cbrt(self) -> Self;
         Complex::exp(self) -> Self;
+        Complex::exp_m1(self) -> Self;
         Complex::expf(self, base: Self::Real) -> Self;
         Complex::ln(self) -> Self;
+        Complex::ln_1p(self) -> Self;
         Complex::sin(self) -> Self;
         Complex::cos(self) -> Self;
         Complex::tan(self) -> Self;
@@ -306,6 +403,16 @@ impl<T: Float + FloatConst> ComplexFloat for Complex<T> {
         Complex::asinh(self) -> Self;
         Complex::acosh(self) -> Self;
         Complex::atanh(self) -> Self;
+        Complex::gamma(self) -> Self;
+        Complex::ln_gamma(self) -> Self;
+        Complex::digamma(self) -> Self;
+        Complex::erf(self) -> Self;
+        Complex::erfc(self) -> Self;
+        Complex::erfcx(self) -> Self;
+        Complex::erfi(self) -> Self;
+        Complex::dawson(self) -> Self;
+        Complex::zeta(self) -> Self;
+        Complex::hurwitz_zeta(self, a: Self::Real) -> Self;
     }
 
     forward_ref! {
@@ -348,6 +455,88 @@ mod test {
         assert!(closef(ComplexFloat::exp(0.), 1.));
     }
 
+    #[test]
+    fn test_exp_m1() {
+        let z = Complex::new(1e-20, -1e-20);
+        assert_eq!(ComplexFloat::exp_m1(z), z);
+        assert_eq!(ComplexFloat::exp_m1(1e-20), 1e-20);
+    }
+
+    #[test]
+    fn test_ln_1p() {
+        let z = Complex::new(1e-20, -1e-20);
+        assert_eq!(ComplexFloat::ln_1p(z), z);
+        assert_eq!(ComplexFloat::ln_1p(1e-20), 1e-20);
+    }
+
+    #[test]
+    fn test_gamma() {
+        assert_eq!(
+            ComplexFloat::gamma(Complex::new(4.0, 0.0)),
+            Complex::new(6.0, 0.0)
+        );
+        assert_eq!(ComplexFloat::gamma(4.0), 6.0);
+        assert!(closef(
+            ComplexFloat::gamma(-0.5),
+            -2.0 * f64::consts::PI.sqrt()
+        ));
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        let z = Complex::new(-0.5, 0.0);
+        assert!(closef(
+            ComplexFloat::ln_gamma(z).re,
+            (2.0 * f64::consts::PI.sqrt()).ln()
+        ));
+        assert!(ComplexFloat::ln_gamma(-0.5).is_nan());
+        assert!(closef(
+            ComplexFloat::ln_gamma(-1.5),
+            ComplexFloat::ln_gamma(z).re - 1.5f64.ln()
+        ));
+        assert_eq!(ComplexFloat::ln_gamma(-1.0), f64::INFINITY);
+        assert!(closef(ComplexFloat::ln_gamma(3.0), 2f64.ln()));
+    }
+
+    #[test]
+    fn test_digamma() {
+        let euler = 0.5772156649015329;
+        assert!(close(
+            ComplexFloat::digamma(_1_0i),
+            Complex::new(-euler, 0.0)
+        ));
+        assert!(closef(ComplexFloat::digamma(2.0), 1.0 - euler));
+    }
+
+    #[test]
+    fn test_erf() {
+        assert!(close(
+            ComplexFloat::erf(_0_1i),
+            Complex::new(0.0, 1.6504257587975428)
+        ));
+        assert!(closef(ComplexFloat::erf(1.0), 0.8427007929497149));
+        assert!(closef(ComplexFloat::erfc(1.0), 0.15729920705028513));
+        assert!(closef(ComplexFloat::erfcx(1.0), 0.427583576155807));
+        assert!(closef(ComplexFloat::erfi(1.0), 1.6504257587975428));
+        assert!(closef(ComplexFloat::dawson(1.0), 0.5380795069127684));
+    }
+
+    #[test]
+    fn test_zeta() {
+        let z = Complex::new(2.0, 1.0);
+        assert!(close(
+            ComplexFloat::zeta(z),
+            Complex::new(1.1503557032549028, -0.4375308659196079)
+        ));
+        assert!(closef(ComplexFloat::zeta(3.0), 1.2020569031595942));
+        assert!(closef(ComplexFloat::hurwitz_zeta(-1.0, 0.25), 1.0 / 96.0));
+        assert!(closef(
+            ComplexFloat::hurwitz_zeta(4.0, -2.5),
+            32.4579793698646
+        ));
+        assert!(ComplexFloat::hurwitz_zeta(0.5, -2.5).is_nan());
+    }
+
     #[test]
     fn test_powi() {
         assert!(close(ComplexFloat::powi(_0_1i, 4), _1_0i));
@@ -399,6 +588,19 @@ mod test {
         assert!(ComplexFloat::is_infinite(f64::INFINITY));
     }
 
+    #[test]
+    fn test_special_values() {
+        let inf = f64::INFINITY;
+        let z = ComplexFloat::sqrt(Complex::new(-inf, f64::NAN));
+        assert!(z.re.is_nan() && z.im.is_infinite());
+        assert_eq!(ComplexFloat::ln(_0_0i), Complex::new(-inf, 0.0));
+        assert_eq!(
+            ComplexFloat::acos(Complex::new(inf, inf)),
+            Complex::new(f64::consts::FRAC_PI_4, -inf)
+        );
+        assert!(ComplexFloat::is_infinite(Complex::new(f64::NAN, inf)));
+    }
+
     #[test]
     fn test_is_finite() {
         assert!(ComplexFloat::is_finite(_1_0i));
@@ -0,0 +1,896 @@
+//! The Eisenstein integers `ℤ[ω]`, where `ω = e^(2πi/3)` is a primitive cube
+//! root of unity, so `ω² = -1 - ω`.  These form the hexagonal lattice in the
+//! complex plane, as the Gaussian integers form the square one.
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{
+    CheckedAdd, CheckedEuclid, CheckedMul, CheckedSub, Euclid, Num, One, Signed, Zero,
+};
+
+use crate::gaussian::{checked_cross, checked_dot, floor_div};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::Complex;
+use crate::{parse_parts, write_complex, ParseComplexError};
+
+/// An Eisenstein integer `a + bω`, with `ω = e^(2πi/3) = (-1 + i√3)/2`.
+///
+/// This has the same arithmetic as [`Complex`](crate::Complex), in the basis
+/// `1, ω` rather than `1, i`.  Like `Complex<T>` for integer `T`, `/` and
+/// `%` truncate the exact quotient towards zero in each coordinate, while
+/// [`div_round`](Eisenstein::div_round), [`Euclid`] and [`CheckedEuclid`]
+/// give the nearest quotient.  It is formatted and parsed like `Complex`,
+/// with `ω` as the unit, or `w` when parsing.
+///
+/// # Examples
+///
+/// ```
+/// use num_complex::Eisenstein;
+///
+/// let w = Eisenstein::<i32>::omega();
+/// assert_eq!(w * w, -1 - w);
+/// assert_eq!(w * w * w, Eisenstein::new(1, 0));
+///
+/// let z = Eisenstein::new(3, -2);
+/// assert_eq!(z.norm(), 19);
+/// assert_eq!(z.to_string(), "3-2ω");
+/// assert_eq!("3 - 2w".parse(), Ok(z));
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+#[repr(C)]
+pub struct Eisenstein<T> {
+    /// Coefficient of `1`
+    pub a: T,
+    /// Coefficient of `ω`
+    pub b: T,
+}
+
+impl<T> Eisenstein<T> {
+    /// Create a new Eisenstein integer `a + bω`
+    #[inline]
+    pub const fn new(a: T, b: T) -> Self {
+        Eisenstein { a, b }
+    }
+}
+
+impl<T: Clone + Num> Eisenstein<T> {
+    /// Returns the cube root of unity `ω`
+    #[inline]
+    pub fn omega() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    /// Returns the norm `a² - ab + b²`, which is the square of the absolute
+    /// value, as [`Complex::norm_sqr`](crate::Complex::norm_sqr) is.
+    #[inline]
+    pub fn norm(&self) -> T {
+        let (a, b) = (self.a.clone(), self.b.clone());
+        a.clone() * a.clone() - a * b.clone() + b.clone() * b
+    }
+
+    /// Multiplies `self` by the scalar `t`.
+    #[inline]
+    pub fn scale(&self, t: T) -> Self {
+        Self::new(self.a.clone() * t.clone(), self.b.clone() * t)
+    }
+
+    /// Divides `self` by the scalar `t`.
+    #[inline]
+    pub fn unscale(&self, t: T) -> Self {
+        Self::new(self.a.clone() / t.clone(), self.b.clone() / t)
+    }
+
+    /// Finds the Eisenstein integer corresponding to the true ratio rounded
+    /// towards zero in each coordinate.
+    fn div_trunc(&self, divisor: &Self) -> Self {
+        let Eisenstein { a, b } = self.clone() / divisor.clone();
+        Self::new(a.clone() - a % T::one(), b.clone() - b % T::one())
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Eisenstein<T> {
+    /// Returns the complex conjugate, `a + bω̄ = (a - b) - bω`.
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self::new(self.a.clone() - self.b.clone(), -self.b.clone())
+    }
+}
+
+impl<T: Clone + Num + Signed + Ord> Eisenstein<T> {
+    /// Returns an Eisenstein integer nearest to `self / divisor`.
+    ///
+    /// The remainder, `self - divisor * quotient`, then has at most a third
+    /// of the norm of the divisor, so this is the quotient of Euclidean
+    /// division, as in [`Euclid`].  When several are nearest, this is the
+    /// first of `q`, `q + 1`, `q + ω` and `q + 1 + ω`, where `q` is the floor
+    /// of the exact quotient in each coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero, as integer division does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Eisenstein;
+    ///
+    /// let (x, y) = (Eisenstein::new(7i32, 5), Eisenstein::new(2, -1));
+    /// let q = x.div_round(&y);
+    /// assert_eq!(q, Eisenstein::new(2, 2));
+    /// assert!(3 * (x - y * q).norm() <= y.norm());
+    /// ```
+    pub fn div_round(&self, divisor: &Self) -> Self {
+        let n = divisor.norm();
+        let z = self * divisor.conj();
+        let q = Self::new(floor_div(z.a, &n), floor_div(z.b, &n));
+        // The exact quotient lies in the cell with corners q and q + 1 + ω,
+        // which its short diagonal splits into two equilateral triangles, so
+        // the nearest corner is the nearest Eisenstein integer.
+        let (zero, one) = (T::zero(), T::one());
+        let corners = [
+            Self::new(zero.clone(), zero.clone()),
+            Self::new(one.clone(), zero.clone()),
+            Self::new(zero, one.clone()),
+            Self::new(one.clone(), one),
+        ];
+        corners
+            .iter()
+            .map(|c| q.clone() + c)
+            .min_by_key(|c| (self - divisor * c).norm())
+            .unwrap()
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, normalized
+    /// by one of the six units, `±1`, `±ω` and `±ω²`, to have `a > b ≥ 0`,
+    /// which is an argument in `[0, π/3)`.  The gcd of zero and zero is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Eisenstein;
+    ///
+    /// // 7 = (3 + ω)(2 - ω)
+    /// let seven = Eisenstein::new(7i64, 0);
+    /// assert_eq!(seven.gcd(&Eisenstein::new(6, 2)), Eisenstein::new(3, 1));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.clone() - b.clone() * a.div_round(&b);
+            a = b;
+            b = r;
+        }
+        // multiplying by -ω² = 1 + ω turns by π/3
+        for _ in 0..5 {
+            if a.is_zero() || a.a > a.b && !a.b.is_negative() {
+                break;
+            }
+            a = Self::new(a.a.clone() - a.b.clone(), a.a);
+        }
+        a
+    }
+}
+
+/// Euclidean division of Eisenstein integers, with the quotient of
+/// [`div_round`](Eisenstein::div_round), so the remainder has at most a third
+/// of the norm of the divisor.
+impl<T: Clone + Num + Signed + Ord> Euclid for Eisenstein<T> {
+    #[inline]
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_round(v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.clone() - v.clone() * self.div_round(v)
+    }
+
+    #[inline]
+    fn div_rem_euclid(&self, v: &Self) -> (Self, Self) {
+        let q = self.div_round(v);
+        (q.clone(), self.clone() - v.clone() * q)
+    }
+}
+
+/// Euclidean division as for [`Euclid`], returning `None` when the divisor is
+/// zero or when any intermediate product overflows.
+impl<T> CheckedEuclid for Eisenstein<T>
+where
+    T: Clone + Num + Signed + Ord + CheckedAdd + CheckedSub + CheckedMul,
+{
+    #[inline]
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+        self.checked_div_rem_euclid(v).map(|(q, _)| q)
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+        self.checked_div_rem_euclid(v).map(|(_, r)| r)
+    }
+
+    fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        let n = checked_norm(v)?;
+        if n.is_zero() {
+            return None;
+        }
+        // self * v.conj(), as in `Div`, without forming `c - d`
+        let (a, b, c, d) = (&self.a, &self.b, &v.a, &v.b);
+        let re = checked_cross(a, c, a, d)?.checked_add(&b.checked_mul(d)?)?;
+        let im = checked_cross(b, c, a, d)?;
+        let q = Self::new(floor_div(re, &n), floor_div(im, &n));
+        // the nearest corner of the cell, as in `div_round`
+        let (zero, one) = (T::zero(), T::one());
+        let corners = [
+            Self::new(zero.clone(), zero.clone()),
+            Self::new(one.clone(), zero.clone()),
+            Self::new(zero, one.clone()),
+            Self::new(one.clone(), one),
+        ];
+        let r = checked_sub(self, &checked_mul(v, &q)?)?;
+        let mut best: Option<(&Self, Self, T)> = None;
+        for corner in &corners {
+            let r = checked_sub(&r, &checked_mul(v, corner)?)?;
+            let norm = checked_norm(&r)?;
+            if best.as_ref().map_or(true, |(_, _, min)| norm < *min) {
+                best = Some((corner, r, norm));
+            }
+        }
+        let (corner, r, _) = best?;
+        let q = Self::new(q.a.checked_add(&corner.a)?, q.b.checked_add(&corner.b)?);
+        Some((q, r))
+    }
+}
+
+/// Returns `x y`, or `None` on overflow.
+fn checked_mul<T>(x: &Eisenstein<T>, y: &Eisenstein<T>) -> Option<Eisenstein<T>>
+where
+    T: CheckedAdd + CheckedSub + CheckedMul,
+{
+    let bd = x.b.checked_mul(&y.b)?;
+    let a = x.a.checked_mul(&y.a)?.checked_sub(&bd)?;
+    let b = checked_dot(&x.a, &y.b, &x.b, &y.a)?.checked_sub(&bd)?;
+    Some(Eisenstein::new(a, b))
+}
+
+/// Returns `x - y`, or `None` on overflow.
+fn checked_sub<T: CheckedSub>(x: &Eisenstein<T>, y: &Eisenstein<T>) -> Option<Eisenstein<T>> {
+    Some(Eisenstein::new(
+        x.a.checked_sub(&y.a)?,
+        x.b.checked_sub(&y.b)?,
+    ))
+}
+
+/// Returns the norm `a² - ab + b²`, or `None` on overflow.
+fn checked_norm<T: CheckedAdd + CheckedSub + CheckedMul>(z: &Eisenstein<T>) -> Option<T> {
+    checked_cross(&z.a, &z.a, &z.a, &z.b)?.checked_add(&z.b.checked_mul(&z.b)?)
+}
+
+/// Converts `a + bω` to `(a - b/2) + i(b√3/2)`.  The real part is exact, and
+/// as `√3` is irrational the imaginary part is rounded, but distinct
+/// Eisenstein integers stay distinct while `b` is exactly representable.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> From<Eisenstein<T>> for Complex<T> {
+    #[inline]
+    fn from(z: Eisenstein<T>) -> Self {
+        let two = T::one() + T::one();
+        let half_sqrt3 = (two + T::one()).sqrt() / two;
+        Complex::new(z.a - z.b / two, z.b * half_sqrt3)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> From<&Eisenstein<T>> for Complex<T> {
+    #[inline]
+    fn from(z: &Eisenstein<T>) -> Self {
+        From::from(*z)
+    }
+}
+
+// Integers that convert to the float without loss, also keep `a - b/2` exact.
+macro_rules! lossless_complex {
+    ($($int:ident => $float:ident),*) => {$(
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl From<Eisenstein<$int>> for Complex<$float> {
+            #[inline]
+            fn from(z: Eisenstein<$int>) -> Self {
+                From::from(Eisenstein::new($float::from(z.a), $float::from(z.b)))
+            }
+        }
+    )*};
+}
+
+lossless_complex!(i8 => f32, i16 => f32, i8 => f64, i16 => f64, i32 => f64);
+
+impl<T: Clone + Num> From<T> for Eisenstein<T> {
+    #[inline]
+    fn from(a: T) -> Self {
+        Self::new(a, T::zero())
+    }
+}
+
+impl<T: Clone + Num> From<&T> for Eisenstein<T> {
+    #[inline]
+    fn from(a: &T) -> Self {
+        From::from(a.clone())
+    }
+}
+
+macro_rules! forward_all_binop {
+    (impl $imp:ident, $method:ident) => {
+        impl<T: Clone + Num> $imp<&Eisenstein<T>> for &Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: &Eisenstein<T>) -> Self::Output {
+                self.clone().$method(other.clone())
+            }
+        }
+
+        impl<T: Clone + Num> $imp<Eisenstein<T>> for &Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: Eisenstein<T>) -> Self::Output {
+                self.clone().$method(other)
+            }
+        }
+
+        impl<T: Clone + Num> $imp<&Eisenstein<T>> for Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: &Eisenstein<T>) -> Self::Output {
+                self.$method(other.clone())
+            }
+        }
+    };
+}
+
+// arithmetic
+forward_all_binop!(impl Add, add);
+
+impl<T: Clone + Num> Add<Eisenstein<T>> for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self::Output {
+        Self::Output::new(self.a + other.a, self.b + other.b)
+    }
+}
+
+forward_all_binop!(impl Sub, sub);
+
+impl<T: Clone + Num> Sub<Eisenstein<T>> for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self::Output {
+        Self::Output::new(self.a - other.a, self.b - other.b)
+    }
+}
+
+forward_all_binop!(impl Mul, mul);
+
+// (a + bω)(c + dω) == ac + (ad + bc)ω + bdω² == (ac - bd) + (ad + bc - bd)ω
+impl<T: Clone + Num> Mul<Eisenstein<T>> for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self::Output {
+        let bd = self.b.clone() * other.b.clone();
+        let a = self.a.clone() * other.a.clone() - bd.clone();
+        let b = self.a * other.b + self.b * other.a - bd;
+        Self::Output::new(a, b)
+    }
+}
+
+forward_all_binop!(impl Div, div);
+
+// (a + bω) / (c + dω) == (a + bω)(c + dω̄) / (c² - cd + d²), where
+// (a + bω)(c + dω̄) == (a + bω)((c - d) - dω) == (ac - ad + bd) + (bc - ad)ω
+impl<T: Clone + Num> Div<Eisenstein<T>> for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self::Output {
+        let norm = other.norm();
+        let ad = self.a.clone() * other.b.clone();
+        let a = self.a * other.a.clone() - ad.clone() + self.b.clone() * other.b;
+        let b = self.b * other.a - ad;
+        Self::Output::new(a / norm.clone(), b / norm)
+    }
+}
+
+forward_all_binop!(impl Rem, rem);
+
+impl<T: Clone + Num> Rem<Eisenstein<T>> for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, modulus: Self) -> Self::Output {
+        let eisenstein = self.div_trunc(&modulus);
+        self - modulus * eisenstein
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Neg for Eisenstein<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::Output::new(-self.a, -self.b)
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Neg for &Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+// Op Assign
+
+mod opassign {
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+    use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+
+    use num_traits::NumAssign;
+
+    use super::Eisenstein;
+
+    macro_rules! forward_op_assign {
+        (impl $imp:ident, $method:ident, $op:ident) => {
+            impl<T: Clone + NumAssign> $imp for Eisenstein<T> {
+                #[inline]
+                fn $method(&mut self, other: Self) {
+                    *self = self.clone().$op(other);
+                }
+            }
+
+            impl<T: Clone + NumAssign> $imp<T> for Eisenstein<T> {
+                #[inline]
+                fn $method(&mut self, other: T) {
+                    *self = self.clone().$op(other);
+                }
+            }
+
+            impl<T: Clone + NumAssign> $imp<&Eisenstein<T>> for Eisenstein<T> {
+                #[inline]
+                fn $method(&mut self, other: &Self) {
+                    self.$method(other.clone())
+                }
+            }
+
+            impl<T: Clone + NumAssign> $imp<&T> for Eisenstein<T> {
+                #[inline]
+                fn $method(&mut self, other: &T) {
+                    self.$method(other.clone())
+                }
+            }
+        };
+    }
+
+    forward_op_assign!(impl AddAssign, add_assign, add);
+    forward_op_assign!(impl SubAssign, sub_assign, sub);
+    forward_op_assign!(impl MulAssign, mul_assign, mul);
+    forward_op_assign!(impl DivAssign, div_assign, div);
+    forward_op_assign!(impl RemAssign, rem_assign, rem);
+}
+
+macro_rules! scalar_arithmetic {
+    (@forward $imp:ident::$method:ident for $($scalar:ident),*) => (
+        impl<T: Clone + Num> $imp<&T> for Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                self.$method(other.clone())
+            }
+        }
+        impl<T: Clone + Num> $imp<T> for &Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: T) -> Self::Output {
+                self.clone().$method(other)
+            }
+        }
+        impl<T: Clone + Num> $imp<&T> for &Eisenstein<T> {
+            type Output = Eisenstein<T>;
+
+            #[inline]
+            fn $method(self, other: &T) -> Self::Output {
+                self.clone().$method(other.clone())
+            }
+        }
+        $(
+            impl $imp<Eisenstein<$scalar>> for $scalar {
+                type Output = Eisenstein<$scalar>;
+
+                #[inline]
+                fn $method(self, other: Eisenstein<$scalar>) -> Self::Output {
+                    Eisenstein::from(self).$method(other)
+                }
+            }
+            impl $imp<&Eisenstein<$scalar>> for $scalar {
+                type Output = Eisenstein<$scalar>;
+
+                #[inline]
+                fn $method(self, other: &Eisenstein<$scalar>) -> Self::Output {
+                    Eisenstein::from(self).$method(other)
+                }
+            }
+            impl $imp<Eisenstein<$scalar>> for &$scalar {
+                type Output = Eisenstein<$scalar>;
+
+                #[inline]
+                fn $method(self, other: Eisenstein<$scalar>) -> Self::Output {
+                    Eisenstein::from(self).$method(other)
+                }
+            }
+            impl $imp<&Eisenstein<$scalar>> for &$scalar {
+                type Output = Eisenstein<$scalar>;
+
+                #[inline]
+                fn $method(self, other: &Eisenstein<$scalar>) -> Self::Output {
+                    Eisenstein::from(self).$method(other)
+                }
+            }
+        )*
+    );
+    ($($scalar:ident),*) => (
+        scalar_arithmetic!(@forward Add::add for $($scalar),*);
+        scalar_arithmetic!(@forward Sub::sub for $($scalar),*);
+        scalar_arithmetic!(@forward Mul::mul for $($scalar),*);
+        scalar_arithmetic!(@forward Div::div for $($scalar),*);
+        scalar_arithmetic!(@forward Rem::rem for $($scalar),*);
+    );
+}
+
+scalar_arithmetic!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f32, f64);
+
+impl<T: Clone + Num> Add<T> for Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn add(self, other: T) -> Self::Output {
+        Self::Output::new(self.a + other, self.b)
+    }
+}
+
+impl<T: Clone + Num> Sub<T> for Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn sub(self, other: T) -> Self::Output {
+        Self::Output::new(self.a - other, self.b)
+    }
+}
+
+impl<T: Clone + Num> Mul<T> for Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        self.scale(other)
+    }
+}
+
+impl<T: Clone + Num> Div<T> for Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn div(self, other: T) -> Self::Output {
+        self.unscale(other)
+    }
+}
+
+impl<T: Clone + Num> Rem<T> for Eisenstein<T> {
+    type Output = Eisenstein<T>;
+
+    #[inline]
+    fn rem(self, other: T) -> Self::Output {
+        Self::Output::new(self.a % other.clone(), self.b % other)
+    }
+}
+
+// constants
+impl<T: Clone + Num> Zero for Eisenstein<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(Zero::zero(), Zero::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+
+    #[inline]
+    fn set_zero(&mut self) {
+        self.a.set_zero();
+        self.b.set_zero();
+    }
+}
+
+impl<T: Clone + Num> One for Eisenstein<T> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(One::one(), Zero::zero())
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.a.is_one() && self.b.is_zero()
+    }
+
+    #[inline]
+    fn set_one(&mut self) {
+        self.a.set_one();
+        self.b.set_zero();
+    }
+}
+
+// string conversions
+macro_rules! fmt_impl {
+    ($($imp:ident, $t:expr, $prefix:expr;)*) => {$(
+        impl<T> fmt::$imp for Eisenstein<T>
+        where
+            T: fmt::$imp + Num + PartialOrd + Clone,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_complex!(f, $t, $prefix, self.a, self.b, T, "ω")
+            }
+        }
+    )*};
+}
+
+fmt_impl! {
+    Display, "", "";
+    LowerExp, "e", "";
+    UpperExp, "E", "";
+    LowerHex, "x", "0x";
+    UpperHex, "X", "0x";
+    Octal, "o", "0o";
+    Binary, "b", "0b";
+}
+
+impl<T> FromStr for Eisenstein<T>
+where
+    T: FromStr + Num + Clone,
+{
+    type Err = ParseComplexError<T::Err>;
+
+    /// Parses `a +/- bω`; `bω +/- a`; `a`; or `bω` where `a` and `b` are of
+    /// type `T`, and `ω` may also be written `w`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = parse_parts(s, ['ω', 'w'], T::from_str)?;
+        Ok(Self::new(a, b))
+    }
+}
+
+impl<T: Num + Clone> Num for Eisenstein<T> {
+    type FromStrRadixErr = ParseComplexError<T::FromStrRadixErr>;
+
+    /// Parses `a +/- bω`; `bω +/- a`; `a`; or `bω` where `a` and `b` are of
+    /// type `T`, and `ω` may also be written `w`
+    ///
+    /// `radix` must be <= 32; larger radix would include *w* as a digit.
+    ///
+    /// The conversion returns an error if 32 < radix <= 36; it panics if
+    /// radix > 36.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        assert!(
+            radix <= 36,
+            "from_str_radix: radix is too high (maximum 36)"
+        );
+
+        // larger radix would include 'w' as a digit, which cannot be supported
+        if radix > 32 {
+            return Err(ParseComplexError::unsupported_radix());
+        }
+
+        let (a, b) = parse_parts(s, ['ω', 'w'], |x| -> Result<T, T::FromStrRadixErr> {
+            T::from_str_radix(x, radix)
+        })?;
+        Ok(Self::new(a, b))
+    }
+}
+
+impl<T: Num + Clone> Sum for Eisenstein<T> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::zero(), |acc, c| acc + c)
+    }
+}
+
+impl<'a, T: 'a + Num + Clone> Sum<&'a Eisenstein<T>> for Eisenstein<T> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Eisenstein<T>>,
+    {
+        iter.fold(Self::zero(), |acc, c| acc + c)
+    }
+}
+
+impl<T: Num + Clone> Product for Eisenstein<T> {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::one(), |acc, c| acc * c)
+    }
+}
+
+impl<'a, T: 'a + Num + Clone> Product<&'a Eisenstein<T>> for Eisenstein<T> {
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Eisenstein<T>>,
+    {
+        iter.fold(Self::one(), |acc, c| acc * c)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Eisenstein;
+    use num_traits::{CheckedEuclid, Euclid, Num, One, Zero};
+    use std::string::ToString;
+
+    fn lattice(r: i64) -> impl Iterator<Item = Eisenstein<i64>> + Clone {
+        (-r..=r).flat_map(move |a| (-r..=r).map(move |b| Eisenstein::new(a, b)))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let w = Eisenstein::<i64>::omega();
+        let one = Eisenstein::one();
+        assert_eq!(w * w + w + one, Eisenstein::zero());
+        assert_eq!(w.conj(), w * w);
+        for x in lattice(4) {
+            assert_eq!(x.conj().conj(), x);
+            assert_eq!(x * x.conj(), Eisenstein::from(x.norm()));
+            for y in lattice(3) {
+                assert_eq!(x * y, y * x);
+                assert_eq!((x * y).norm(), x.norm() * y.norm());
+                assert_eq!((x * y).conj(), x.conj() * y.conj());
+                assert_eq!(x + y - y, x);
+                if !y.is_zero() {
+                    assert_eq!(x * y / y, x);
+                    let (q, r) = (x / y, x % y);
+                    assert_eq!(y * q + r, x);
+                }
+            }
+        }
+
+        let mut z = Eisenstein::new(3i64, -2);
+        z += w;
+        z *= Eisenstein::new(1, 1);
+        z -= &one;
+        z *= 2;
+        z /= Eisenstein::new(1, 1);
+        z %= 5;
+        let x = (Eisenstein::new(3, -1) * Eisenstein::new(1, 1) - one) * 2;
+        assert_eq!(z, x / Eisenstein::new(1, 1) % 5);
+        assert_eq!(2 * w + 1, Eisenstein::new(1, 2));
+        assert_eq!(&w - 1, -Eisenstein::new(1, -1));
+        let v = [w, one, w];
+        assert_eq!(v.iter().sum::<Eisenstein<i64>>(), Eisenstein::new(1, 2));
+        assert_eq!(v.iter().product::<Eisenstein<i64>>(), w * w);
+    }
+
+    #[test]
+    fn test_euclid() {
+        for x in lattice(8) {
+            for y in lattice(3) {
+                if y.is_zero() {
+                    assert_eq!(x.checked_div_euclid(&y), None);
+                    assert_eq!(x.checked_rem_euclid(&y), None);
+                    continue;
+                }
+                let (q, r) = x.div_rem_euclid(&y);
+                assert_eq!(q, x.div_round(&y));
+                assert_eq!(r, x.rem_euclid(&y));
+                assert_eq!(x.checked_div_rem_euclid(&y), Some((q, r)));
+                assert_eq!(y * q + r, x);
+                assert!(3 * r.norm() <= y.norm(), "{} {}", x, y);
+                // no other quotient does better
+                for d in lattice(1) {
+                    assert!(r.norm() <= (r - y * d).norm());
+                }
+            }
+        }
+
+        let max = Eisenstein::new(i32::MAX, i32::MAX);
+        assert_eq!(max.checked_div_euclid(&Eisenstein::new(2, 1)), None);
+        assert_eq!(
+            max.checked_div_rem_euclid(&Eisenstein::one()),
+            Some((max, Eisenstein::zero()))
+        );
+        // dividing by ω multiplies by ω² = -1 - ω
+        let w = Eisenstein::omega();
+        let (x, y) = (Eisenstein::new(i32::MAX, 0), Eisenstein::new(i32::MIN, 0));
+        assert_eq!(x.checked_div_euclid(&w), Some(-x - x * w));
+        assert_eq!(y.checked_div_euclid(&w), None);
+    }
+
+    #[test]
+    fn test_gcd() {
+        let units = [(1, 0), (1, 1), (0, 1), (-1, 0), (-1, -1), (0, -1)];
+        for x in lattice(7) {
+            for y in lattice(3) {
+                let g = x.gcd(&y);
+                assert_eq!(g, y.gcd(&x));
+                if g.is_zero() {
+                    assert!(x.is_zero() && y.is_zero());
+                    continue;
+                }
+                assert!(g.a > g.b && g.b >= 0);
+                assert!((x % g).is_zero() && (y % g).is_zero());
+                assert!((x / g).gcd(&(y / g)).is_one());
+                for &(a, b) in &units {
+                    assert_eq!((x * Eisenstein::new(a, b)).gcd(&y), g);
+                }
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_to_complex() {
+        use crate::{Complex, Complex64};
+
+        let c = |z: Eisenstein<i64>| Complex64::from(Eisenstein::new(z.a as i32, z.b as i32));
+        let w = Complex64::from(Eisenstein::new(0, 1));
+        assert!((w - Complex64::new(0.0, 2.0 * core::f64::consts::FRAC_PI_3).exp()).norm() < 1e-15);
+        for x in lattice(5) {
+            for y in lattice(3) {
+                let z = c(x * y);
+                let p = c(x) * c(y);
+                assert!((z - p).norm() <= 1e-14 * z.norm());
+            }
+        }
+        let big = Eisenstein::new(i32::MAX, i32::MIN + 1);
+        let z = Complex::<f64>::from(big);
+        assert_eq!(z.re, f64::from(i32::MAX) - f64::from(i32::MIN + 1) / 2.0);
+        assert_eq!(Complex::<f32>::from(Eisenstein::new(-3i16, 5)).re, -5.5);
+    }
+
+    #[test]
+    fn test_string() {
+        let cases = [
+            ("3-2ω", Eisenstein::new(3, -2)),
+            ("-1+0ω", Eisenstein::new(-1, 0)),
+            ("0+7ω", Eisenstein::new(0, 7)),
+        ];
+        for &(s, z) in &cases {
+            assert_eq!(z.to_string(), s);
+            assert_eq!(s.parse(), Ok(z));
+        }
+        assert_eq!(format!("{:x}", Eisenstein::new(-255, 16)), "-ff+10ω");
+        assert_eq!(format!("{:#b}", Eisenstein::new(5, -2)), "0b101-0b10ω");
+
+        assert_eq!("ω".parse(), Ok(Eisenstein::new(0, 1)));
+        assert_eq!("-w".parse(), Ok(Eisenstein::new(0, -1)));
+        assert_eq!("4w - 1".parse(), Ok(Eisenstein::new(-1, 4)));
+        assert_eq!("12".parse(), Ok(Eisenstein::new(12, 0)));
+        assert!("1+2i".parse::<Eisenstein<i32>>().is_err());
+        assert!("1--2ω".parse::<Eisenstein<i32>>().is_err());
+
+        assert_eq!(
+            Eisenstein::from_str_radix("v+1fω", 32),
+            Ok(Eisenstein::new(31, 47))
+        );
+        assert!(Eisenstein::<i32>::from_str_radix("1+w", 33).is_err());
+    }
+}
@@ -0,0 +1,628 @@
+//! The arithmetic–geometric mean, the complete elliptic integrals, and
+//! Carlson's symmetric elliptic integrals.
+//!
+//! The AGM takes the "right choice" of square root at each step, as in
+//! D. A. Cox, "The arithmetic-geometric mean of Gauss", Enseign. Math. 30,
+//! 1984.  Carlson's integrals use the duplication algorithms of B. C. Carlson,
+//! "Numerical computation of real or complex elliptic integrals", Numer.
+//! Algorithms 10(1), 1995, which also gives their domains of validity.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::{constant, Complex};
+
+/// The AGM converges quadratically once its terms are close, and the
+/// duplication algorithms reduce their error by 4^6 per step, so these are
+/// far more steps than they ever need.
+const AGM_STEPS: u32 = 64;
+const DUPLICATION_STEPS: u32 = 128;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the arithmetic–geometric mean of `self` and `other`.
+    ///
+    /// Each step replaces `a` and `b` with `(a + b)/2` and `±√(ab)`, with the
+    /// "right choice" of sign: the one for which `|a' - b'| ≤ |a' + b'|`,
+    /// taking `Im(b'/a') > 0` on a tie.  This makes the mean homogeneous,
+    /// `agm(λa, λb) = λ agm(a, b)` for every complex `λ` unless `b/a` is a
+    /// negative real, where the first step is a tie, and equal to the usual
+    /// real mean for positive arguments.  Which root
+    /// [`sqrt`](Complex::sqrt) returns doesn't matter.  The mean is zero if
+    /// either argument is zero or if `a = -b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // Gauss's constant, 1/agm(1, √2)
+    /// let m = Complex64::new(1.0, 0.0).agm(Complex64::new(2f64.sqrt(), 0.0));
+    /// assert!((m.re.recip() - 0.8346268416740732).abs() < 1e-15);
+    /// assert_eq!(m.im, 0.0);
+    ///
+    /// let m = Complex64::new(1.0, 0.0).agm(Complex64::new(0.0, 1.0));
+    /// assert!((m - Complex64::new(0.5990701173677961, 0.5990701173677961)).norm() < 1e-15);
+    /// ```
+    pub fn agm(self, other: Self) -> Self {
+        let eps = T::epsilon();
+        let two = T::one() + T::one();
+        let (mut a, mut b) = (self, other);
+        for _ in 0..AGM_STEPS {
+            if a.is_zero() || b.is_zero() {
+                return Self::zero();
+            }
+            if (a - b).norm() <= eps * a.norm() {
+                break;
+            }
+            let mean = a.unscale(two) + b.unscale(two);
+            // √a √b is one of the roots of ab, and doesn't overflow
+            let mut root = a.sqrt() * b.sqrt();
+            // |a' - b'| ≤ |a' + b'| exactly when Re(b'/a') ≥ 0, which doesn't
+            // round to a tie when |b'| is much smaller than |a'|
+            let ratio = root.fdiv(mean);
+            if ratio.re < T::zero() || (ratio.re.is_zero() && ratio.im < T::zero()) {
+                root = -root;
+            }
+            a = mean;
+            b = root;
+        }
+        a
+    }
+
+    /// Computes the complete elliptic integral of the first kind, `K(m)`, of
+    /// the parameter `m = self`, `∫₀^(π/2) (1 - m sin²θ)^(-1/2) dθ`.
+    ///
+    /// This is `π / (2 agm(1, √(1 - m)))`, with the principal square root.  The
+    /// cut is along `[1, ∞)`, where the sign of a zero imaginary part selects
+    /// the side, as for [`sqrt`](Complex::sqrt): `K` is continuous with the
+    /// upper half-plane on `+0.0` and with the lower on `-0.0`.  Note that the
+    /// parameter is `m = k²`, the square of the modulus.  `K(1)` is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_eq!(Complex64::new(0.0, 0.0).elliptic_k(), Complex64::new(FRAC_PI_2, 0.0));
+    ///
+    /// let k = Complex64::new(0.5, 0.0).elliptic_k();
+    /// assert!((k.re - 1.854074677301372).abs() < 1e-15);
+    ///
+    /// // above and below the cut
+    /// let k = Complex64::new(2.0, 0.0).elliptic_k();
+    /// assert!((k - Complex64::new(1.3110287771460598, 1.3110287771460598)).norm() < 1e-15);
+    /// let k = Complex64::new(2.0, -0.0).elliptic_k();
+    /// assert_eq!(k, Complex64::new(2.0, 0.0).elliptic_k().conj());
+    /// ```
+    pub fn elliptic_k(self) -> Self {
+        if self.is_one() {
+            return Self::new(T::infinity(), T::zero());
+        }
+        // 1 - m, keeping the sign of a zero imaginary part
+        let w = Self::new(T::one() - self.re, -self.im);
+        let mean = Self::one().agm(w.sqrt());
+        let k = Self::from(T::FRAC_PI_2()).fdiv(mean);
+        if self.im.is_zero() && self.re < T::one() {
+            Self::new(k.re, T::zero())
+        } else {
+            k
+        }
+    }
+
+    /// Computes the complete elliptic integral of the second kind, `E(m)`, of
+    /// the parameter `m = self`, `∫₀^(π/2) (1 - m sin²θ)^(1/2) dθ`.
+    ///
+    /// This is `(1 - m)/3 (R_D(0, 1 - m, 1) + R_D(0, 1, 1 - m))`, which has no
+    /// cancellation for real `m < 1`.  The cut and its sides are as for
+    /// [`elliptic_k`](Complex::elliptic_k), and `E(1) = 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_eq!(Complex64::new(0.0, 0.0).elliptic_e(), Complex64::new(FRAC_PI_2, 0.0));
+    ///
+    /// let e = Complex64::new(0.5, 0.0).elliptic_e();
+    /// assert!((e.re - 1.3506438810476755).abs() < 1e-15);
+    ///
+    /// // Legendre's relation, E K' + E' K - K K' = π/2, with m' = 1 - m
+    /// let m = Complex64::new(0.3, 0.4);
+    /// let m1 = 1.0 - m;
+    /// let (k, e) = (m.elliptic_k(), m.elliptic_e());
+    /// let (k1, e1) = (m1.elliptic_k(), m1.elliptic_e());
+    /// assert!((e * k1 + e1 * k - k * k1 - FRAC_PI_2).norm() < 1e-14);
+    /// ```
+    pub fn elliptic_e(self) -> Self {
+        if self.is_one() {
+            return Self::one();
+        }
+        if self.is_zero() {
+            return Self::new(T::FRAC_PI_2(), T::zero());
+        }
+        let zero = Self::zero();
+        let one = Self::one();
+        let w = Self::new(T::one() - self.re, -self.im);
+        let e = (w * (zero.carlson_rd(w, one) + zero.carlson_rd(one, w))).unscale(constant(3.0));
+        if self.im.is_zero() && self.re < T::one() {
+            Self::new(e.re, T::zero())
+        } else {
+            e
+        }
+    }
+
+    /// Computes Carlson's symmetric elliptic integral of the first kind,
+    /// `R_F(x, y, z) = ½ ∫₀^∞ ((t + x)(t + y)(t + z))^(-1/2) dt`, with
+    /// `x = self`.
+    ///
+    /// The duplication algorithm gives the integral, with the square root
+    /// continuous from its positive value at `t = ∞`, for `x`, `y` and `z` off
+    /// the negative real axis with at most one of them zero.  On the axis,
+    /// the sign of a zero imaginary part selects the side, as for
+    /// [`sqrt`](Complex::sqrt).  With two of them zero, the integral diverges,
+    /// and this returns `+∞`.  `R_F(x, x, x) = x^(-1/2)`.
+    ///
+    /// Any incomplete elliptic integral of the first kind follows, as in
+    /// `F(φ | m) = sin φ R_F(cos² φ, 1 - m sin² φ, 1)` for `|Re φ| ≤ π/2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let (x, y, z) = (Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0), Complex64::new(0.0, 0.0));
+    /// assert!((x.carlson_rf(y, z).re - 1.3110287771460598).abs() < 1e-15);
+    ///
+    /// // F(φ | m)
+    /// let (phi, m) = (Complex64::new(1.0, 0.5), Complex64::new(0.7, 0.0));
+    /// let (s, c) = (phi.sin(), phi.cos());
+    /// let f = s * (c * c).carlson_rf(1.0 - m * s * s, Complex64::new(1.0, 0.0));
+    /// assert!((f - Complex64::new(1.0135199222550537, 0.6799893790982946)).norm() < 1e-15);
+    /// ```
+    pub fn carlson_rf(self, y: Self, z: Self) -> Self {
+        let args = [self, y, z];
+        if args.iter().filter(|v| v.is_zero()).count() > 1 {
+            return Self::new(T::infinity(), T::zero());
+        }
+        let a0 = (self + y + z).unscale(constant(3.0));
+        let tol = constant::<T>(3.0) * T::epsilon();
+        let (a, scale) = duplicate(args, a0, tol, |_, _, _| {});
+        // X + Y + Z = 0
+        let inv_a = a.finv();
+        let x = (a0 - self).scale(scale) * inv_a;
+        let y = (a0 - y).scale(scale) * inv_a;
+        let z = -(x + y);
+        let e2 = x * y - z * z;
+        let e3 = x * y * z;
+        let series = Self::one() - e2.unscale(constant(10.0))
+            + e3.unscale(constant(14.0))
+            + (e2 * e2).unscale(constant(24.0))
+            - (e2 * e3).scale(constant(3.0 / 44.0));
+        series * a.sqrt().finv()
+    }
+
+    /// Computes Carlson's symmetric elliptic integral of the second kind,
+    /// `R_D(x, y, z) = 3/2 ∫₀^∞ ((t + x)(t + y))^(-1/2) (t + z)^(-3/2) dt`,
+    /// with `x = self`.
+    ///
+    /// The domain and branches are as for [`carlson_rf`](Complex::carlson_rf),
+    /// except that `z` must not be zero.  `R_D(x, x, x) = x^(-3/2)`.
+    ///
+    /// Any incomplete elliptic integral of the second kind follows, as in
+    /// `E(φ | m) = sin φ R_F(c², Δ², 1) - m/3 sin³ φ R_D(c², Δ², 1)`, where
+    /// `c = cos φ` and `Δ² = 1 - m sin² φ`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let x = Complex64::new(0.0, 0.0);
+    /// let rd = x.carlson_rd(Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0));
+    /// assert!((rd.re - 1.7972103521033886).abs() < 1e-15);
+    ///
+    /// let z = Complex64::new(0.5, -1.5);
+    /// assert!((z.carlson_rd(z, z) - z.powf(-1.5)).norm() < 1e-15);
+    /// ```
+    pub fn carlson_rd(self, y: Self, z: Self) -> Self {
+        let args = [self, y, z];
+        let a0 = (self + y + z.scale(constant(3.0))).unscale(constant(5.0));
+        let tol = T::epsilon() / constant(4.0);
+        let mut sum = Self::zero();
+        let (a, scale) = duplicate(args, a0, tol, |args, lambda, scale| {
+            let z = args[2];
+            sum = sum + (z.sqrt() * (z + lambda)).finv().scale(scale);
+        });
+        // X + Y + 3Z = 0
+        let inv_a = a.finv();
+        let x = (a0 - self).scale(scale) * inv_a;
+        let y = (a0 - y).scale(scale) * inv_a;
+        let z = -(x + y).unscale(constant(3.0));
+        let xy = x * y;
+        let z2 = z * z;
+        let e2 = xy - z2.scale(constant(6.0));
+        let e3 = (xy.scale(constant(3.0)) - z2.scale(constant(8.0))) * z;
+        let e4 = (xy - z2).scale(constant(3.0)) * z2;
+        let e5 = xy * z * z2;
+        let series = carlson_series(e2, e3, e4, e5);
+        series.scale(scale) * inv_a * a.sqrt().finv() + sum.scale(constant(3.0))
+    }
+
+    /// Computes Carlson's symmetric elliptic integral of the third kind,
+    /// `R_J(x, y, z, p) = 3/2 ∫₀^∞ ((t + x)(t + y)(t + z))^(-1/2) (t + p)^-1 dt`,
+    /// with `x = self`.
+    ///
+    /// The duplication algorithm gives the integral when `x`, `y` and `z` are
+    /// real and non-negative, with at most one of them zero, and `p` is off
+    /// the non-positive real axis; or when all four arguments have
+    /// non-negative real parts, with `p` non-zero and at most one of the
+    /// others zero.  Elsewhere, the result may be on another sheet.  Its
+    /// Cauchy principal value for negative `p` is not provided.
+    /// `R_J(x, y, z, z) = R_D(x, y, z)`.
+    ///
+    /// The `f64` result is within a few times `1e-15` relative error, but up
+    /// to about `1e-13` when `p` is much smaller than `x`, `y` and `z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let c = |re| Complex64::new(re, 0.0);
+    /// let rj = c(2.0).carlson_rj(c(3.0), c(4.0), c(5.0));
+    /// assert!((rj.re - 0.14297579667156753).abs() < 1e-16);
+    ///
+    /// let rj = c(2.0).carlson_rj(c(3.0), c(4.0), Complex64::new(-0.5, 1.0));
+    /// assert!((rj - Complex64::new(0.22331995940085084, -0.35782770898686234)).norm() < 1e-15);
+    /// ```
+    pub fn carlson_rj(self, y: Self, z: Self, p: Self) -> Self {
+        let args = [self, y, z, p];
+        let a0 = (self + y + z + p.scale(constant(2.0))).unscale(constant(5.0));
+        let tol = T::epsilon() / constant(4.0);
+        let delta = (p - self) * (p - y) * (p - z);
+        let mut sum = Self::zero();
+        let (a, scale) = duplicate(args, a0, tol, |args, _, scale| {
+            let sp = args[3].sqrt();
+            let d = (sp + args[0].sqrt()) * (sp + args[1].sqrt()) * (sp + args[2].sqrt());
+            let e = delta.scale(scale * scale * scale) / (d * d);
+            sum = sum + (carlson_rc_one(e) / d).scale(scale);
+        });
+        // X + Y + Z - 2P = 0
+        let inv_a = a.finv();
+        let x = (a0 - self).scale(scale) * inv_a;
+        let y = (a0 - y).scale(scale) * inv_a;
+        let z = (a0 - z).scale(scale) * inv_a;
+        let p = -(x + y + z).unscale(constant(2.0));
+        let xyz = x * y * z;
+        let p2 = p * p;
+        let e2 = x * y + x * z + y * z - p2.scale(constant(3.0));
+        let e3 = xyz + (e2 * p).scale(constant(2.0)) + (p2 * p).scale(constant(4.0));
+        let e4 = (xyz.scale(constant(2.0)) + e2 * p + (p2 * p).scale(constant(3.0))) * p;
+        let e5 = xyz * p2;
+        let series = carlson_series(e2, e3, e4, e5);
+        series.scale(scale) * inv_a * a.sqrt().finv() + sum.scale(constant(6.0))
+    }
+}
+
+/// Runs the duplication `v ↦ (v + λ)/4` on `args` and their mean `a0`, with
+/// `λ = √x√y + √y√z + √z√x` from the first three, until the arguments are
+/// within `tol^(1/6)` of their mean.  Before each step, `step` gets the
+/// arguments, `λ` and the scale `4^-m` so far.  Returns the final mean and
+/// scale.
+fn duplicate<T, const N: usize, F>(
+    mut args: [Complex<T>; N],
+    a0: Complex<T>,
+    tol: T,
+    mut step: F,
+) -> (Complex<T>, T)
+where
+    T: Float + FloatConst,
+    F: FnMut(&[Complex<T>; N], Complex<T>, T),
+{
+    let four = constant::<T>(4.0);
+    let q = args.iter().fold(T::zero(), |q, &v| q.max((a0 - v).norm()))
+        * tol.powf(constant(-1.0 / 6.0));
+    let (mut a, mut scale) = (a0, T::one());
+    for _ in 0..DUPLICATION_STEPS {
+        if q * scale < a.norm() {
+            break;
+        }
+        let (sx, sy, sz) = (args[0].sqrt(), args[1].sqrt(), args[2].sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        step(&args, lambda, scale);
+        a = (a + lambda).unscale(four);
+        for v in args.iter_mut() {
+            *v = (*v + lambda).unscale(four);
+        }
+        scale = scale / four;
+    }
+    (a, scale)
+}
+
+/// The series that finishes `R_D` and `R_J`, to fifth order.
+fn carlson_series<T: Float>(
+    e2: Complex<T>,
+    e3: Complex<T>,
+    e4: Complex<T>,
+    e5: Complex<T>,
+) -> Complex<T> {
+    Complex::<T>::one() - e2.scale(constant(3.0 / 14.0))
+        + e3.unscale(constant(6.0))
+        + (e2 * e2).scale(constant(9.0 / 88.0))
+        - e4.scale(constant(3.0 / 22.0))
+        - (e2 * e3).scale(constant(9.0 / 52.0))
+        + e5.scale(constant(3.0 / 26.0))
+}
+
+/// Returns `R_C(1, 1 + e) = atan(√e) / √e`, which is analytic in `e` off
+/// `(-∞, -1]`.
+fn carlson_rc_one<T: Float>(e: Complex<T>) -> Complex<T> {
+    if e.norm() < constant(0.1) {
+        // Σ (-e)^k / (2k + 1)
+        let mut sum = Complex::zero();
+        let mut power = Complex::one();
+        let mut k = T::one();
+        loop {
+            let term = power / k;
+            sum = sum + term;
+            if term.norm() <= T::epsilon() * sum.norm() {
+                return sum;
+            }
+            power = -power * e;
+            k = k + constant(2.0);
+        }
+    }
+    let r = e.sqrt();
+    r.atan() / r
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::Complex64;
+    use num_traits::{One, Zero};
+    use std::f64::consts::FRAC_PI_2;
+
+    // (m, K(m), E(m)) with references from mpmath
+    type Case = ((f64, f64), (f64, f64), (f64, f64));
+
+    // (arguments, R(arguments)) with references from mpmath
+    type CarlsonCase<const N: usize> = ([(f64, f64); N], (f64, f64));
+
+    fn c((re, im): (f64, f64)) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    fn close_rel(a: Complex64, b: Complex64, tol: f64) -> bool {
+        close_to_tol(a, b, tol * b.norm())
+    }
+
+    #[test]
+    fn test_elliptic_k_e() {
+        let cases: [Case; 9] = [
+            (
+                (0.5, 0.0),
+                (1.8540746773013719, 0.0),
+                (1.3506438810476755, 0.0),
+            ),
+            (
+                (-3.0, 0.0),
+                (1.0782578237498217, 0.0),
+                (2.422112055136919, 0.0),
+            ),
+            (
+                (0.99, 0.0),
+                (3.695637362989874, 0.0),
+                (1.015993545025224, 0.0),
+            ),
+            (
+                (2.0, 0.0),
+                (1.3110287771460598, 1.3110287771460598),
+                (0.5990701173677961, -0.5990701173677961),
+            ),
+            (
+                (0.3, 0.4),
+                (1.65024192564194, 0.20951070412398676),
+                (1.462512810717238, -0.1751606054169262),
+            ),
+            (
+                (-5.0, 7.0),
+                (0.8135474800944554, 0.2271973507356352),
+                (3.0921619779200373, -1.1869197282612132),
+            ),
+            (
+                (1.5, -2.0),
+                (1.2036726910750775, -0.6521705447320614),
+                (1.3709363977128608, 0.9252796559171116),
+            ),
+            (
+                (0.0, -0.001),
+                (1.5707961059017805, -0.0003926989283007405),
+                (1.5707964004259576, 0.000392699051019119),
+            ),
+            (
+                (1e6, 1.0),
+                (0.0015708003665210405, 0.008294050678214078),
+                (0.0012854002100849845, -999.9956029752261),
+            ),
+        ];
+        for &(m, k, e) in &cases {
+            let m = c(m);
+            assert!(close_rel(m.elliptic_k(), c(k), 1e-14));
+            assert!(close_rel(m.elliptic_e(), c(e), 1e-14));
+            // the lower side of the cut is the conjugate
+            assert!(close_rel(m.conj().elliptic_k(), c(k).conj(), 1e-14));
+            assert!(close_rel(m.conj().elliptic_e(), c(e).conj(), 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_elliptic_special() {
+        let one = Complex64::one();
+        assert_eq!(Complex64::zero().elliptic_k(), c((FRAC_PI_2, 0.0)));
+        assert_eq!(Complex64::zero().elliptic_e(), c((FRAC_PI_2, 0.0)));
+        assert!(one.elliptic_k().re.is_infinite());
+        assert_eq!(one.elliptic_e(), one);
+        for &m in &[(0.25, 0.0), (3.0, 1.0), (-2.0, -0.5), (0.5, 10.0)] {
+            let (m, m1) = (c(m), one - c(m));
+            let (k, e) = (m.elliptic_k(), m.elliptic_e());
+            let (k1, e1) = (m1.elliptic_k(), m1.elliptic_e());
+            // Legendre's relation
+            assert!(close_to_tol(
+                e * k1 + e1 * k - k * k1,
+                c((FRAC_PI_2, 0.0)),
+                1e-14
+            ));
+            // K and E in terms of Carlson's forms
+            let zero = Complex64::zero();
+            assert!(close_rel(k, zero.carlson_rf(m1, one), 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_agm() {
+        let cases: [CarlsonCase<2>; 6] = [
+            ([(1.0, 0.0), (2.0, 0.0)], (1.4567910310469068, 0.0)),
+            (
+                [(1.0, 0.0), (-1.5, 0.0)],
+                (-0.2682552874177697, -0.5098648415190643),
+            ),
+            (
+                [(1.0, 2.0), (-3.0, 1.0)],
+                (-1.0200309434883148, 1.9549145095164882),
+            ),
+            (
+                [(0.0, 1.0), (-1.0, -1.0)],
+                (-0.7780040787889583, 0.18841106798868001),
+            ),
+            (
+                [(1000.0, 0.0), (-1.0, 0.01)],
+                (165.7586254405857, 62.58603737481524),
+            ),
+            ([(2.0, -1.0), (2.0, 1.0)], (2.116387937880026, 0.0)),
+        ];
+        for &([a, b], m) in &cases {
+            let (a, b) = (c(a), c(b));
+            assert!(close_rel(a.agm(b), c(m), 1e-14));
+            assert!(close_rel(b.agm(a), c(m), 1e-14));
+            // homogeneity, except on a tie at the first step
+            let ratio = b.fdiv(a);
+            if ratio.im != 0.0 || ratio.re > 0.0 {
+                let s = c((-0.5, 3.0));
+                assert!(close_rel((a * s).agm(b * s), c(m) * s, 1e-14));
+            }
+        }
+        let one = Complex64::one();
+        assert!(one.agm(-one).is_zero());
+        assert!(one.agm(Complex64::zero()).is_zero());
+        assert_eq!(one.agm(one), one);
+        // a + b overflows
+        let (a, b) = (c((f64::MAX, 0.0)), c((f64::MAX / 2.0, f64::MAX / 2.0)));
+        let s = 2f64.powi(-1000);
+        assert!(close_rel(a.agm(b), (a * s).agm(b * s) / s, 1e-14));
+    }
+
+    #[test]
+    fn test_carlson_rf() {
+        let cases: [CarlsonCase<3>; 5] = [
+            (
+                [(1.0, 0.0), (2.0, 0.0), (3.0, 0.0)],
+                (0.7269459354689082, 0.0),
+            ),
+            (
+                [(1.0, 1.0), (2.0, -1.0), (0.0, 0.0)],
+                (1.2007745771695095, -0.07482522901689788),
+            ),
+            (
+                [(-1.0, 1.0), (0.0, 2.0), (3.0, -1.0)],
+                (0.6785656734094977, -0.39108422225232126),
+            ),
+            (
+                [(0.0, 1.0), (0.0, -1.0), (2.0, 0.0)],
+                (1.044144565406436, 0.0),
+            ),
+            (
+                [(0.5, 0.0), (1e3, 0.0), (1e-3, 0.0)],
+                (0.16265238468175056, 0.0),
+            ),
+        ];
+        for &([x, y, z], r) in &cases {
+            let (x, y, z) = (c(x), c(y), c(z));
+            assert!(close_rel(x.carlson_rf(y, z), c(r), 1e-14));
+            // symmetry
+            assert!(close_rel(z.carlson_rf(x, y), c(r), 1e-14));
+        }
+        let x = c((0.3, -2.0));
+        assert!(close_rel(x.carlson_rf(x, x), x.sqrt().inv(), 1e-15));
+        let zero = Complex64::zero();
+        assert_eq!(
+            zero.carlson_rf(zero, Complex64::one()),
+            c((f64::INFINITY, 0.0))
+        );
+        assert_eq!(x.carlson_rf(zero, zero), c((f64::INFINITY, 0.0)));
+    }
+
+    #[test]
+    fn test_carlson_rd() {
+        let cases: [CarlsonCase<3>; 5] = [
+            (
+                [(0.0, 0.0), (2.0, 0.0), (1.0, 0.0)],
+                (1.7972103521033884, 0.0),
+            ),
+            (
+                [(2.0, 0.0), (3.0, 0.0), (4.0, 0.0)],
+                (0.16510527294261054, 0.0),
+            ),
+            (
+                [(-1.0, 1.0), (0.0, 2.0), (3.0, -1.0)],
+                (0.25818296652748624, -0.22307481632324047),
+            ),
+            (
+                [(0.0, 1.0), (0.0, -1.0), (2.0, 0.0)],
+                (0.6593385415421977, 0.0),
+            ),
+            (
+                [(1.0, -2.0), (1e-3, 0.0), (0.5, 5.0)],
+                (0.07662939728143292, -0.3188308745115945),
+            ),
+        ];
+        for &([x, y, z], r) in &cases {
+            let (x, y, z) = (c(x), c(y), c(z));
+            assert!(close_rel(x.carlson_rd(y, z), c(r), 1e-14));
+            assert!(close_rel(y.carlson_rd(x, z), c(r), 1e-14));
+            assert!(close_rel(x.carlson_rj(y, z, z), c(r), 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_carlson_rj() {
+        let cases: [CarlsonCase<4>; 5] = [
+            (
+                [(2.0, 0.0), (3.0, 0.0), (4.0, 0.0), (5.0, 0.0)],
+                (0.14297579667156754, 0.0),
+            ),
+            (
+                [(-1.0, 1.0), (0.0, 2.0), (3.0, -1.0), (2.0, 0.5)],
+                (0.17611334097295753, -0.37193702468766493),
+            ),
+            (
+                [(0.0, 1.0), (0.0, -1.0), (2.0, 0.0), (-0.5, 1.0)],
+                (0.45740402728690577, -1.125336600533433),
+            ),
+            (
+                [(1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (0.01, -1.0)],
+                (0.4228149092776038, 0.472294182709392),
+            ),
+            (
+                [(0.0, 0.0), (1.0, 1.0), (2.0, -3.0), (4.0, 2.0)],
+                (0.41643098208361373, -0.10124131135640374),
+            ),
+        ];
+        for &([x, y, z, p], r) in &cases {
+            let (x, y, z, p) = (c(x), c(y), c(z), c(p));
+            assert!(close_rel(x.carlson_rj(y, z, p), c(r), 1e-14));
+            assert!(close_rel(z.carlson_rj(x, y, p), c(r), 1e-14));
+        }
+    }
+}
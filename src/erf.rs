@@ -0,0 +1,446 @@
+//! The Faddeeva function and the complex error functions.
+//!
+//! Everything here is built on `w(z) = exp(-z²) erfc(-iz)`, which in the upper
+//! half-plane is computed with J. A. C. Weideman's rational approximation from
+//! "Computation of the Complex Error Function", SIAM J. Numer. Anal. 31(5),
+//! 1994, and with its asymptotic series far from the origin.
+
+use num_traits::{Float, FloatConst};
+
+use crate::compensated::{two_prod, two_sum};
+use crate::{constant, horner, Complex};
+
+/// Weideman's `L = √(N/√2)`, for `N = 40`.
+const WEIDEMAN_L: f64 = 5.3182958969449885;
+
+/// The coefficients `a_n` of Weideman's approximation with `N = 40` terms,
+/// for `n` from 40 down to 1.
+const WEIDEMAN: [f64; 40] = [
+    -1.899694947394927e-15,
+    1.128073562364402e-15,
+    1.1357687198999241e-14,
+    -5.409310282882142e-15,
+    -7.074086260286855e-14,
+    1.37256205867155e-14,
+    4.5329666782606727e-13,
+    1.2031458219387989e-13,
+    -2.907688342182867e-12,
+    -2.7276023158200452e-12,
+    1.7714495214011192e-11,
+    3.47272670930455e-11,
+    -9.055124450928292e-11,
+    -3.5632339865976533e-10,
+    2.1086006347066517e-10,
+    3.0177805400090707e-09,
+    3.2497465180436973e-09,
+    -1.8315616783040462e-08,
+    -6.35177348504429e-08,
+    1.4198642399935674e-08,
+    5.912136951899494e-07,
+    1.483566113220078e-06,
+    -1.0660138984947143e-06,
+    -1.8007447144750956e-05,
+    -5.591309264248318e-05,
+    -3.939363145489569e-05,
+    0.0004398070159869668,
+    0.0027054056330737914,
+    0.010048186242783424,
+    0.029202916471241867,
+    0.07182361779074337,
+    0.15504263802479495,
+    0.29989437996150065,
+    0.5266528988277086,
+    0.8472174576593818,
+    1.2563815675765133,
+    1.7253830848179779,
+    2.201513794878312,
+    2.61605415276186,
+    2.8996245093897053,
+];
+
+/// Beyond this magnitude, `w` is computed with its asymptotic series.
+const ASYMPTOTIC_MIN: f64 = 50.0;
+
+/// `(2k - 1)!! / 2^k`, for `k` from 5 down to 0.
+const ASYMPTOTIC: [f64; 6] = [29.53125, 6.5625, 1.875, 0.75, 0.5, 1.0];
+
+/// Below this magnitude, `erf` and `dawson` are computed with their Taylor
+/// series, which need this many terms.
+const TAYLOR_MAX: f64 = 1.0;
+const TAYLOR_TERMS: u32 = 20;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the Faddeeva function, `w(self) = exp(-self²) erfc(-i self)`.
+    ///
+    /// This is also known as the plasma dispersion function up to a factor of
+    /// `i√π`, and its real part on the real line gives the Voigt profile.  The
+    /// `f64` result is within about `1e-15` relative error in the upper
+    /// half-plane, measured over both parts together, so the smaller part can
+    /// be less accurate where the two parts differ greatly in size.  The lower
+    /// half-plane uses `w(z) = 2 exp(-z²) - w(-z)`, where the error grows in
+    /// proportion to `|self|²` just as the function's sensitivity to rounding
+    /// `self` does, and the result overflows where `exp(-z²)` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let w = Complex64::new(1.0, 1.0).faddeeva();
+    /// assert!((w - Complex64::new(0.3047442052569126, 0.20821893820283163)).norm() < 1e-15);
+    ///
+    /// // On the real line, the real part is exp(-x²).
+    /// assert_eq!(Complex64::new(2.0, 0.0).faddeeva().re, (-4.0f64).exp());
+    /// ```
+    pub fn faddeeva(self) -> Self {
+        if self.im < T::zero() {
+            return exp_neg_sqr(self).scale(T::one() + T::one()) - (-self).faddeeva();
+        }
+        let w = if self.norm() > constant(ASYMPTOTIC_MIN) {
+            // w(z) ~ i/(√π z) Σ (2k - 1)!! / (2z²)^k
+            let r = self.finv();
+            (horner(&ASYMPTOTIC, r * r) * r).mul_i()
+        } else {
+            // w(z) = 2 p(Z) / (L - iz)² + 1 / (√π (L - iz)), with Z = (L + iz) / (L - iz)
+            let l = constant::<T>(WEIDEMAN_L);
+            let d = Self::new(l + self.im, -self.re);
+            let n = Self::new(l - self.im, self.re);
+            let r = d.finv();
+            let p = horner(&WEIDEMAN, n * r);
+            let two = T::one() + T::one();
+            let two_sqrt_pi = (two + two) / T::FRAC_2_SQRT_PI();
+            (p.scale(two_sqrt_pi) * r + T::one()) * r
+        };
+        let w = w.scale(T::FRAC_2_SQRT_PI() / (T::one() + T::one()));
+        if self.im.is_zero() {
+            Self::new(exp_neg_sqr(self).re, w.im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the scaled complementary error function,
+    /// `erfcx(self) = exp(self²) erfc(self)`.
+    ///
+    /// This is `w(i self)`, and unlike `erfc` it doesn't underflow for large
+    /// positive real parts.
+    #[inline]
+    pub fn erfcx(self) -> Self {
+        self.mul_i().faddeeva()
+    }
+
+    /// Computes the complementary error function, `erfc(self) = 1 - erf(self)`.
+    pub fn erfc(self) -> Self {
+        if self.re.is_sign_negative() {
+            let two = Self::from(T::one() + T::one());
+            let w = two - (-self).erfc();
+            return if self.im.is_zero() {
+                Self::new(w.re, -self.im)
+            } else {
+                w
+            };
+        }
+        let w = mul_exp_neg_sqr(self, self.erfcx());
+        if self.im.is_zero() {
+            Self::new(w.re, -self.im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the error function, `erf(self) = 2/√π ∫₀^self exp(-t²) dt`.
+    ///
+    /// As for the other error functions, the `f64` result is within a few
+    /// times `1e-15` relative error near the origin, measured over both parts
+    /// together, and the error grows in proportion to `|self|²` wherever
+    /// `exp(-self²)` contributes to the result.  That factor is applied in
+    /// steps where it overflows on its own, so the result is infinite only
+    /// where its true value overflows.  Real arguments give real results, and
+    /// imaginary arguments imaginary results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let z = Complex64::new(0.5, 0.0).erf();
+    /// assert!((z.re - 0.5204998778130465).abs() < 1e-16);
+    /// assert_eq!(z.im, 0.0);
+    /// ```
+    pub fn erf(self) -> Self {
+        let Complex { re, im } = self;
+        let w = if self.norm() < constant(TAYLOR_MAX) {
+            // erf(z) = 2/√π Σ (-1)^n z^(2n+1) / (n! (2n + 1))
+            let zz = -(self * self);
+            let mut term = self;
+            let mut sum = self;
+            for n in 1..TAYLOR_TERMS {
+                let n = T::from(n).unwrap();
+                term = term * zz / n;
+                sum = sum + term / (n + n + T::one());
+            }
+            sum.scale(T::FRAC_2_SQRT_PI())
+        } else if re.is_sign_negative() {
+            (-self).erfc() - T::one()
+        } else {
+            Self::from(T::one()) - self.erfc()
+        };
+        if im.is_zero() {
+            Self::new(w.re, im)
+        } else if re.is_zero() {
+            Self::new(re, w.im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the imaginary error function, `erfi(self) = -i erf(i self)`.
+    #[inline]
+    pub fn erfi(self) -> Self {
+        self.mul_i().erf().mul_neg_i()
+    }
+
+    /// Computes Dawson's integral, `F(self) = exp(-self²) ∫₀^self exp(t²) dt`.
+    ///
+    /// This is `√π/2 exp(-self²) erfi(self)`, computed with its Taylor series
+    /// near the origin and from `w` elsewhere, so it doesn't overflow on the
+    /// real line.
+    pub fn dawson(self) -> Self {
+        let Complex { re, im } = self;
+        let two = T::one() + T::one();
+        let w = if self.norm() < constant(TAYLOR_MAX) {
+            // F(z) = Σ (-2z²)^n z / (2n + 1)!!
+            let zz = -(self * self).scale(two);
+            let mut term = self;
+            let mut sum = self;
+            for n in 1..TAYLOR_TERMS {
+                let n = T::from(n).unwrap();
+                term = term * zz / (n + n + T::one());
+                sum = sum + term;
+            }
+            sum
+        } else if im.is_sign_negative() {
+            -(-self).dawson()
+        } else {
+            // F(z) = i√π/2 (exp(-z²) - w(z))
+            let sqrt_pi = two / T::FRAC_2_SQRT_PI();
+            (exp_neg_sqr(self) - self.faddeeva())
+                .mul_i()
+                .scale(sqrt_pi / two)
+        };
+        if im.is_zero() {
+            Self::new(w.re, im)
+        } else if re.is_zero() {
+            Self::new(re, w.im)
+        } else {
+            w
+        }
+    }
+}
+
+/// Computes `exp(-z²)`, with the real part of `-z²` carried to twice the
+/// working precision so the result is accurate on the real line.
+fn exp_neg_sqr<T: Float>(z: Complex<T>) -> Complex<T> {
+    let Complex { re: x, im: y } = z;
+    if !(x.is_finite() && y.is_finite()) {
+        return (-(z * z)).exp();
+    }
+    // exp(hi + lo) ≈ exp(hi) (1 + lo) for tiny lo.
+    let (hi, lo, theta) = neg_sqr(x, y);
+    let m = hi.exp();
+    // An overflowed m can't take the correction, which would give ∞ - ∞.
+    let m = if m.is_finite() { m + m * lo } else { m };
+    if theta.is_zero() {
+        return Complex::new(m, theta);
+    }
+    let (sin, cos) = theta.sin_cos();
+    Complex::new(m * cos, m * sin)
+}
+
+/// Computes `exp(-z²) w`.  Where `exp(-z²)` overflows, `w` is rotated first
+/// and then scaled up in steps, so the product overflows only where it is
+/// really that large, and to infinities of the right signs.
+fn mul_exp_neg_sqr<T: Float>(z: Complex<T>, w: Complex<T>) -> Complex<T> {
+    let Complex { re: x, im: y } = z;
+    let finite = |z: Complex<T>| z.re.is_finite() && z.im.is_finite();
+    let ln_max = T::max_value().ln();
+    if !(finite(z) && finite(w)) || neg_sqr(x, y).0 <= ln_max {
+        return exp_neg_sqr(z) * w;
+    }
+    let (hi, lo, theta) = neg_sqr(x, y);
+    let mut f = w.scale(T::one() + lo);
+    if !theta.is_zero() {
+        f = f * Complex::cis(theta);
+    }
+    // Each step is at most √MAX, so this ends once each part is zero or
+    // infinite, after a few steps at most.
+    let half_max = ln_max / (T::one() + T::one());
+    let steps = (hi / half_max).ceil();
+    let step = if hi.is_finite() { hi / steps } else { half_max }.exp();
+    let done = |t: T| t.is_zero() || t.is_infinite();
+    let mut k = T::zero();
+    while k < steps && !(done(f.re) && done(f.im)) {
+        f = f.scale(step);
+        k = k + T::one();
+    }
+    f
+}
+
+/// Returns `-z²` for `z = x + iy`, as `hi + lo - 2ixy` with the real part
+/// `y² - x²` carried to twice the working precision in `hi + lo`.
+fn neg_sqr<T: Float>(x: T, y: T) -> (T, T, T) {
+    let (xx, xx_err) = two_prod(x, x);
+    let (yy, yy_err) = two_prod(y, y);
+    let (hi, err) = two_sum(yy, -xx);
+    // The error terms are NaN once a square overflows.
+    let lo = if hi.is_finite() {
+        err + yy_err - xx_err
+    } else {
+        T::zero()
+    };
+    (hi, lo, -(x + x) * y)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::Complex;
+    use std::f64;
+
+    type Case = ((f64, f64), (f64, f64));
+
+    fn check(f: fn(Complex<f64>) -> Complex<f64>, cases: &[Case]) {
+        for &((x, y), (u, v)) in cases {
+            let expected = Complex::new(u, v);
+            let w = f(Complex::new(x, y));
+            assert!(close_to_tol(w, expected, 4e-15 * expected.norm()));
+        }
+    }
+
+    #[test]
+    fn test_faddeeva() {
+        // Reference values, computed with mpmath at 50 digits.
+        let cases: [Case; 10] = [
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((1.0, 1.0), (0.3047442052569126, 0.20821893820283163)),
+            ((1.5, 0.0), (0.10539922456186433, 0.4832273301407691)),
+            ((-3.0, 0.5), (0.03712636605469234, -0.19298375530036208)),
+            ((0.1, 10.0), (0.05613551456287315, 0.0005558774892126873)),
+            ((6.0, 0.01), (0.00016375289889683183, 0.09539592338660148)),
+            ((30.0, 30.0), (0.009405769534934072, 0.009400545563354871)),
+            ((100.0, 1.0), (5.6421779161441334e-05, 0.005641613670145867)),
+            ((1.0, -1.0), (-1.1370378783511974, 2.026813791854195)),
+            (
+                (-1e4, 1e-4),
+                (5.641895920106002e-13, -5.641895863687042e-05),
+            ),
+        ];
+        check(Complex::faddeeva, &cases);
+        let z = Complex::new(1e300, 1e300).faddeeva();
+        assert!(z.norm() < 1e-300);
+        let z = Complex::new(f64::INFINITY, 0.0).faddeeva();
+        assert_eq!(z, Complex::new(0.0, 0.0));
+
+        // exp(-z²) overflows, and so does w on the axis, but not off it
+        let z = Complex::new(0.0, -27.0).faddeeva();
+        assert_eq!(z, Complex::new(f64::INFINITY, 0.0));
+        let z = Complex::new(-0.5, -26.6).faddeeva();
+        let expected = Complex::new(3.134596750920185e306, -3.016671329227289e307);
+        assert!(close_to_tol(z, expected, 1e-13 * expected.norm()));
+    }
+
+    #[test]
+    fn test_erf() {
+        let cases: [Case; 6] = [
+            ((0.5, 0.0), (0.5204998778130465, 0.0)),
+            ((1.0, 1.0), (1.3161512816979477, 0.19045346923783468)),
+            ((-0.3, 0.4), (-0.3820432325830179, 0.4312520362319642)),
+            ((2.0, -3.0), (-20.829461427614568, -8.687318271470163)),
+            ((0.0, 2.0), (0.0, 18.564802414575553)),
+            ((-6.0, 0.0), (-1.0, 0.0)),
+        ];
+        check(Complex::erf, &cases);
+        let z = Complex::new(0.0, 2.0).erf();
+        assert_eq!(z.re, 0.0);
+        let z = Complex::new(-1e-20, 0.0).erf();
+        assert_eq!(z, Complex::new(-1e-20 * f64::consts::FRAC_2_SQRT_PI, 0.0));
+        assert_eq!(
+            Complex::new(f64::INFINITY, 0.0).erf(),
+            Complex::new(1.0, 0.0)
+        );
+
+        // Near overflow, exp(-z²) overflows on its own but the result doesn't,
+        // and beyond it the infinities have the signs of the true value.
+        let z = Complex::new(26.7, 0.0).erfi();
+        assert!(close_to_tol(
+            z,
+            Complex::new(8.499867261268985e307, 0.0),
+            1e294
+        ));
+        assert_eq!(z.im, 0.0);
+        let z = Complex::new(1.0, 26.7).erf();
+        let expected = Complex::new(1.3920225419811011e306, -3.121624159369983e307);
+        assert!(close_to_tol(z, expected, 1e-13 * expected.norm()));
+        assert_eq!(Complex::new(27.0, 0.0).erfi().re, f64::INFINITY);
+        assert_eq!(
+            Complex::new(0.0, 27.0).erf(),
+            Complex::new(0.0, f64::INFINITY)
+        );
+        let inf = f64::INFINITY;
+        assert_eq!(Complex::new(7.87, 90.07).erf(), Complex::new(-inf, -inf));
+        assert_eq!(Complex::new(15.34, 78.73).erf(), Complex::new(inf, -inf));
+        assert_eq!(Complex::new(0.0, 1e200).erf(), Complex::new(0.0, inf));
+    }
+
+    #[test]
+    fn test_erfc() {
+        let cases: [Case; 6] = [
+            ((0.5, 0.0), (0.4795001221869535, 0.0)),
+            ((10.0, 0.0), (2.088487583762545e-45, 0.0)),
+            ((26.0, 0.0), (5.663192408856143e-296, 0.0)),
+            ((-2.0, 0.0), (1.9953222650189528, 0.0)),
+            ((1.0, 1.0), (-0.31615128169794765, -0.19045346923783468)),
+            ((3.0, -2.0), (0.001036721143182731, -1.1546724379290603e-05)),
+        ];
+        check(Complex::erfc, &cases);
+        assert_eq!(
+            Complex::new(f64::INFINITY, 0.0).erfc(),
+            Complex::new(0.0, -0.0)
+        );
+        assert_eq!(
+            Complex::new(-f64::INFINITY, 0.0).erfc(),
+            Complex::new(2.0, -0.0)
+        );
+    }
+
+    #[test]
+    fn test_erfcx() {
+        let cases: [Case; 4] = [
+            ((1.0, 0.0), (0.427583576155807, 0.0)),
+            ((1e5, 0.0), (5.6418958351954685e-06, 0.0)),
+            ((-1.0, 0.5), (1.8964059595453004, -3.689990588519449)),
+            ((2.0, 2.0), (0.14795275951201584, -0.13117971708421786)),
+        ];
+        check(Complex::erfcx, &cases);
+    }
+
+    #[test]
+    fn test_erfi_dawson() {
+        let cases: [Case; 3] = [
+            ((1.0, 0.0), (1.6504257587975428, 0.0)),
+            ((0.5, 0.5), (0.4578813944351922, 0.6426129148548205)),
+            ((-2.0, 1.0), (5.049143703447035, -0.536643565778565)),
+        ];
+        check(Complex::erfi, &cases);
+
+        let cases: [Case; 5] = [
+            ((1.0, 0.0), (0.5380795069127684, 0.0)),
+            ((0.92413887, 0.0), (0.5410442246351816, 0.0)),
+            ((30.0, 0.0), (0.016675941401059175, 0.0)),
+            ((0.5, -0.5), (0.6291446977136278, -0.30523946561753884)),
+            ((3.0, 4.0), (-880.0425388545045, 412.1644959539187)),
+        ];
+        check(Complex::dawson, &cases);
+        assert_eq!(Complex::new(1e300, 0.0).dawson().re, 0.5e-300);
+    }
+}
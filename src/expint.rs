@@ -0,0 +1,622 @@
+//! The exponential integrals `E_n` and `Ei`, and the sine and cosine
+//! integrals.
+//!
+//! `E_n` is summed from its power series, DLMF 8.19.8, near the origin and
+//! along the negative real axis, and from its asymptotic expansion far along
+//! that axis.  Elsewhere it comes from its continued fraction, evaluated with
+//! Lentz's method as in W. H. Press et al., "Numerical Recipes", §6.3.  `Ei`,
+//! `Si` and `Ci` follow from `E_1` with DLMF 6.2.4 and 6.5.5–6, except for
+//! the power series of `Si` and `Ci` near the origin.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::{constant, Complex};
+
+/// Euler's constant, `γ = -ψ(1)`.
+const EULER: f64 = 0.5772156649015329;
+
+/// Up to this magnitude, `E_n` is summed from its power series.
+const SERIES_MAX: f64 = 1.0;
+
+/// Near the negative real axis, where `Im(z)² ≤ CUT_WIDTH |Re(z)|`, the
+/// continued fraction converges slowly, and the power series is used instead.
+/// Its terms then cancel by at most `e^(CUT_WIDTH/2)`.
+const CUT_WIDTH: f64 = 4.0;
+
+/// Near the negative real axis, and this far beyond `n`, the asymptotic
+/// expansion of `E_n` is tried first.
+const ASYMPTOTIC_MIN: f64 = 40.0;
+
+/// The most terms taken from the continued fraction, which needs far fewer
+/// wherever it is used.
+const FRACTION_TERMS: u32 = 10_000;
+
+/// Up to this magnitude, `Si` and `Ci` are summed from their power series.
+const TRIG_SERIES_MAX: f64 = 2.0;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the exponential integral `E₁(self) = ∫₁^∞ e^(-self t) / t dt`.
+    ///
+    /// This is [`expint_en`](Complex::expint_en) with `n = 1`, the principal
+    /// branch with the cut along the negative real axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let e1 = Complex64::new(1.0, 0.0).expint_e1();
+    /// assert!((e1.re - 0.21938393439552027).abs() < 1e-15);
+    /// assert_eq!(e1.im, 0.0);
+    ///
+    /// // E₁(-x ± i0) = -Ei(x) ∓ iπ
+    /// let e1 = Complex64::new(-1.0, 0.0).expint_e1();
+    /// assert!((e1 - Complex64::new(-1.8951178163559368, -std::f64::consts::PI)).norm() < 1e-15);
+    /// ```
+    #[inline]
+    pub fn expint_e1(self) -> Self {
+        self.expint_en(1)
+    }
+
+    /// Computes the generalized exponential integral
+    /// `E_n(self) = ∫₁^∞ e^(-self t) / t^n dt`, for integer `n`.
+    ///
+    /// For `n ≥ 1`, this is the principal branch, with the cut along the
+    /// negative real axis, where `E_n` jumps by `2πi (-z)^(n-1) / (n-1)!`.  As
+    /// for [`ln`](Complex::ln), the sign of a zero imaginary part selects the
+    /// side of the cut, and arguments on the positive real axis give real
+    /// results.  `E_0(z) = e^-z / z` is single-valued.
+    ///
+    /// The `f64` result is within about `1e-14` relative error, except near
+    /// the zeros of `E_n` off the real axis.  `E_n(0)` is `1/(n - 1)` for
+    /// `n ≥ 2`, and infinite otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let e = Complex64::new(2.0, 1.0).expint_en(3);
+    /// assert!((e - Complex64::new(0.009506890417283611, -0.027538794357954918)).norm() < 1e-16);
+    ///
+    /// // E_(n+1)(z) = (e^-z - z E_n(z)) / n
+    /// let z = Complex64::new(-3.0, 0.5);
+    /// let expected = ((-z).exp() - z * z.expint_en(2)) / 2.0;
+    /// assert!((z.expint_en(3) - expected).norm() < 1e-14);
+    /// ```
+    pub fn expint_en(self, n: u32) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return if re == T::infinity() && im.is_finite() {
+                Self::zero()
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if self.is_zero() {
+            return match n {
+                0 => Self::new(T::infinity(), T::nan()),
+                // E₁(z) ~ -ln z
+                1 => -self.ln(),
+                _ => Self::new(T::from(n - 1).unwrap().recip(), T::zero()),
+            };
+        }
+        if n == 0 {
+            return (-self).exp().fdiv(self);
+        }
+
+        let near_cut = re < T::zero() && im * im <= constant::<T>(CUT_WIDTH) * -re;
+        let far = self.norm() >= constant::<T>(ASYMPTOTIC_MIN) + T::from(n).unwrap();
+        let asymptotic = if near_cut && far {
+            expint_asymptotic(self, n)
+        } else {
+            None
+        };
+        let w = match asymptotic {
+            Some(w) => w,
+            None if near_cut || self.norm() <= constant(SERIES_MAX) => expint_series(self, n),
+            None => expint_fraction(self, n),
+        };
+        if im.is_zero() && re > T::zero() {
+            Self::new(w.re, im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the exponential integral `Ei(self)`, the principal value of
+    /// `∫_-∞^self e^t / t dt`.
+    ///
+    /// This is `γ + ln z + Σ z^k / (k k!)`, with the cut of `ln` along the
+    /// negative real axis, so `Ei(-x ± i0) = -E₁(x) ± iπ`.  Arguments on the
+    /// positive real axis give real results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let ei = Complex64::new(1.0, 0.0).expint_ei();
+    /// assert!((ei.re - 1.8951178163559368).abs() < 1e-15);
+    /// assert_eq!(ei.im, 0.0);
+    ///
+    /// let ei = Complex64::new(-1.0, 0.0).expint_ei();
+    /// assert!((ei - Complex64::new(-0.21938393439552027, std::f64::consts::PI)).norm() < 1e-15);
+    /// ```
+    pub fn expint_ei(self) -> Self {
+        // Ei(z) = -E₁(-z) + ln z - ln(-z), and the logarithms differ by ±iπ
+        let w = -(-self).expint_e1();
+        if self.im.is_zero() && self.re > T::zero() {
+            return Self::new(w.re, self.im);
+        }
+        let pi = if self.im.is_sign_negative() {
+            -T::PI()
+        } else {
+            T::PI()
+        };
+        Self::new(w.re, w.im + pi)
+    }
+
+    /// Computes the sine integral `Si(self) = ∫₀^self sin(t) / t dt`.
+    ///
+    /// `Si` is entire and odd, and real on the real axis.  The `f64` result is
+    /// within a few times `1e-15` relative error, except near its zeros off
+    /// the real axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let si = Complex64::new(1.0, 0.0).sin_integral();
+    /// assert!((si.re - 0.946083070367183).abs() < 1e-15);
+    /// assert_eq!(si.im, 0.0);
+    ///
+    /// // Si(iy) = i Shi(y)
+    /// let si = Complex64::new(0.0, 1.0).sin_integral();
+    /// assert!((si - Complex64::new(0.0, 1.0572508753757286)).norm() < 1e-15);
+    /// ```
+    pub fn sin_integral(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            // Si(±∞) = ±π/2
+            return if re.is_infinite() && im.is_zero() {
+                Self::new(T::FRAC_PI_2().copysign(re), im)
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        let w = if self.norm() <= constant(TRIG_SERIES_MAX) {
+            // Σ (-1)^k z^(2k+1) / ((2k+1) (2k+1)!)
+            trig_series(self, T::one())
+        } else {
+            // Si(z) = π/2 + (E₁(iz) - E₁(-iz)) / 2i, for Re(z) ≥ 0, and Si is odd
+            let negative = re.is_sign_negative();
+            let z = if negative { -self } else { self };
+            let iz = Complex::new(-z.im, z.re);
+            let d = (iz.expint_e1() - (-iz).expint_e1()).unscale(T::one() + T::one());
+            let w = Complex::new(d.im + T::FRAC_PI_2(), -d.re);
+            if negative {
+                -w
+            } else {
+                w
+            }
+        };
+        if im.is_zero() {
+            Self::new(w.re, im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the cosine integral
+    /// `Ci(self) = γ + ln(self) + ∫₀^self (cos(t) - 1) / t dt`.
+    ///
+    /// This is the principal branch, with the cut of `ln` along the negative
+    /// real axis, so `Ci(-x ± i0) = Ci(x) ± iπ`.  Arguments on the positive
+    /// real axis give real results.  The `f64` result is within a few times
+    /// `1e-15` relative error, except near its zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let ci = Complex64::new(1.0, 0.0).cos_integral();
+    /// assert!((ci.re - 0.33740392290096816).abs() < 1e-15);
+    /// assert_eq!(ci.im, 0.0);
+    ///
+    /// let ci = Complex64::new(-1.0, 0.0).cos_integral();
+    /// assert!((ci - Complex64::new(0.33740392290096816, std::f64::consts::PI)).norm() < 1e-15);
+    /// ```
+    pub fn cos_integral(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            // Ci(+∞) = 0, and Ci(-∞ ± i0) = ±iπ
+            return if re.is_infinite() && im.is_zero() {
+                let pi = if re > T::zero() { T::zero() } else { T::PI() };
+                Self::new(T::zero(), pi.copysign(im))
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        let w = if self.norm() <= constant(TRIG_SERIES_MAX) {
+            // γ + ln z + Σ (-1)^k z^(2k) / (2k (2k)!)
+            let gamma = Self::from(constant::<T>(EULER));
+            gamma + self.ln() + trig_series(self, T::zero())
+        } else if re.is_sign_negative() {
+            // Ci(-z) = Ci(z) ∓ iπ, with the sign of Im(z)
+            let w = (-self).cos_integral();
+            Complex::new(w.re, w.im + T::PI().copysign(im))
+        } else {
+            // Ci(z) = -(E₁(iz) + E₁(-iz)) / 2, for Re(z) ≥ 0
+            let iz = Complex::new(-im, re);
+            -(iz.expint_e1() + (-iz).expint_e1()).unscale(T::one() + T::one())
+        };
+        if im.is_zero() && re > T::zero() {
+            Self::new(w.re, im)
+        } else {
+            w
+        }
+    }
+}
+
+/// Returns `Σ (-1)^k z^(2k+p) / ((2k+p) (2k+p)!)`, for `k ≥ 1 - p` and `p`
+/// either 0 or 1: the power series of `Si`, and that of `Ci` without its
+/// logarithm.
+fn trig_series<T: Float>(z: Complex<T>, p: T) -> Complex<T> {
+    let one = T::one();
+    let z2 = -z * z;
+    let mut term = if p.is_zero() { Complex::one() } else { z };
+    let mut sum = if p.is_zero() { Complex::zero() } else { z };
+    let mut m = p;
+    loop {
+        term = term * z2.unscale((m + one) * (m + one + one));
+        m = m + one + one;
+        let next = term.unscale(m);
+        sum = sum + next;
+        if next.norm() <= T::epsilon() * sum.norm() {
+            return sum;
+        }
+    }
+}
+
+/// The power series of `E_n(z)` for `n ≥ 1`,
+///
+/// `(-z)^(n-1) / (n-1)! (ψ(n) - ln z) - Σ (-z)^k / ((k - n + 1) k!)`,
+///
+/// where the sum skips `k = n - 1`.
+fn expint_series<T: Float + FloatConst>(z: Complex<T>, n: u32) -> Complex<T> {
+    let m = n - 1;
+    // ψ(n) = -γ + Σ 1/j
+    let mut psi = -constant::<T>(EULER);
+    for j in 1..n {
+        psi = psi + T::from(j).unwrap().recip();
+    }
+    let mut term = Complex::<T>::one();
+    let mut sum = Complex::zero();
+    let mut singular = Complex::zero();
+    let mut k = 0;
+    loop {
+        if k == m {
+            singular = term * (Complex::from(psi) - z.ln());
+        } else {
+            let next = term.unscale(T::from(k).unwrap() - T::from(m).unwrap());
+            sum = sum - next;
+            if k > m && next.norm() <= T::epsilon() * sum.norm() {
+                break;
+            }
+        }
+        k += 1;
+        term = term * (-z).unscale(T::from(k).unwrap());
+        if term.is_zero() {
+            break;
+        }
+    }
+    sum + singular
+}
+
+/// The asymptotic expansion of `E_n(z)` near the negative real axis,
+///
+/// `e^-z / z Σ (-1)^k (n)_k / z^k ∓ iπ (-z)^(n-1) / (n-1)!`,
+///
+/// with the sign of `Im(z)`, if its terms get small enough before they start
+/// growing.  Far from the origin, the second part is the jump across the
+/// cut, and it is below the precision of the first everywhere else.
+fn expint_asymptotic<T: Float + FloatConst>(z: Complex<T>, n: u32) -> Option<Complex<T>> {
+    let eps = T::epsilon();
+    let r = -z.finv();
+    let mut term = Complex::<T>::one();
+    let mut sum = Complex::<T>::one();
+    let mut last = T::infinity();
+    let mut k = T::from(n).unwrap();
+    loop {
+        term = term * r.scale(k);
+        let size = term.norm();
+        if size >= last {
+            return None;
+        }
+        sum = sum + term;
+        if size <= eps * sum.norm() {
+            break;
+        }
+        last = size;
+        k = k + T::one();
+    }
+    let mut jump = Complex::<T>::one();
+    for j in 1..n {
+        jump = jump * (-z).unscale(T::from(j).unwrap());
+    }
+    let pi = T::PI().copysign(z.im);
+    let jump = Complex::new(-jump.im, jump.re).scale(pi);
+    Some((-z).exp() * z.finv() * sum - jump)
+}
+
+/// Returns `E_n(z)` for `n ≥ 1` from its continued fraction,
+/// `e^-z / (z + n - 1 n / (z + n + 2 - 2 (n + 1) / (z + n + 4 - ...)))`,
+/// evaluated with Lentz's method.
+fn expint_fraction<T: Float>(z: Complex<T>, n: u32) -> Complex<T> {
+    let eps = T::epsilon();
+    let tiny = Complex::from(T::min_positive_value().sqrt());
+    let one = T::one();
+    let two = one + one;
+    let nm1 = T::from(n - 1).unwrap();
+
+    let mut b = z + T::from(n).unwrap();
+    let mut c = tiny.finv();
+    let mut d = b.finv();
+    let mut h = d;
+    let mut i = T::zero();
+    for _ in 0..FRACTION_TERMS {
+        i = i + one;
+        let a = -i * (nm1 + i);
+        b = b + two;
+        d = d.scale(a) + b;
+        if d.is_zero() {
+            d = tiny;
+        }
+        c = b + c.finv().scale(a);
+        if c.is_zero() {
+            c = tiny;
+        }
+        d = d.finv();
+        let delta = c * d;
+        h = h * delta;
+        if (delta - one).norm() < eps {
+            break;
+        }
+    }
+    (-z).exp() * h
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::all_consts;
+    use crate::test::float::close_to_tol;
+    use crate::Complex64;
+    use std::f64;
+
+    // (n, z, E_n(z)) with references from mpmath
+    type Case = (u32, (f64, f64), (f64, f64));
+
+    // (z, Si(z), Ci(z)) with references from mpmath
+    type TrigCase = ((f64, f64), (f64, f64), (f64, f64));
+
+    #[test]
+    fn test_expint_en() {
+        let cases: [Case; 16] = [
+            (1, (0.5, 0.0), (0.5597735947761608, 0.0)),
+            (1, (3.0, -2.0), (-0.00909592087479473, 0.006900179262212492)),
+            (1, (-2.5, 0.5), (-6.713040868510743, -0.757382904105631)),
+            (
+                1,
+                (-10.0, 0.001),
+                (-2492.2279850509863, -0.9389463751374746),
+            ),
+            (
+                1,
+                (-45.0, 2.0),
+                (2.970979264396514e17, 7.358568048053859e17),
+            ),
+            (
+                1,
+                (0.0, -20.0),
+                (-0.044419820845353314, 0.02255462575145678),
+            ),
+            (
+                1,
+                (25.0, 30.0),
+                (2.9663534243971746e-13, 1.85401806861382e-13),
+            ),
+            (1, (-8.0, 20.0), (-139.90719396839714, -11.908885551924362)),
+            (2, (1.0, 1.0), (0.01915995085507258, -0.13051696506573468)),
+            (2, (-5.0, 0.1), (-52.67904786178772, -11.693391952547104)),
+            (3, (0.1, 0.0), (0.41629145790827876, 0.0)),
+            (
+                3,
+                (-60.0, -1.0),
+                (-1.1128666937200086e24, -1.6679619284630752e24),
+            ),
+            (
+                5,
+                (2.0, -7.0),
+                (0.00032232685010372375, 0.013678760792092572),
+            ),
+            (10, (0.5, 0.5), (0.053727697011198255, -0.03352157657651363)),
+            (10, (-15.0, 3.0), (313712.51888250984, 420652.41197631805)),
+            (0, (2.0, 1.0), (0.006472643426350235, -0.06017667874535916)),
+        ];
+        for &(n, (x, y), (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 1e-14 * expected.norm();
+            assert!(close_to_tol(
+                Complex64::new(x, y).expint_en(n),
+                expected,
+                tol
+            ));
+        }
+
+        // E_(n+1)(z) = (e^-z - z E_n(z)) / n
+        for &z in all_consts.iter().skip(1) {
+            for n in 1..4 {
+                let expected = ((-z).exp() - z * z.expint_en(n)) / f64::from(n);
+                let w = z.expint_en(n + 1);
+                assert!(close_to_tol(w, expected, 1e-14 * w.norm().max(1.0)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_expint_cut() {
+        // (n, x, E_n(x + i0)), the upper side of the cut
+        let cases: [(u32, f64, (f64, f64)); 4] = [
+            (1, -1.5, (-3.301285449129798, -f64::consts::PI)),
+            (1, -50.0, (-1.058563689713169e20, -f64::consts::PI)),
+            (2, -0.5, (1.4216113182685413, -f64::consts::FRAC_PI_2)),
+            (3, -45.0, (-8.332642929187514e17, -3180.862561759666)),
+        ];
+        for &(n, x, (u, v)) in cases.iter() {
+            let above = Complex64::new(x, 0.0).expint_en(n);
+            assert!((above.re / u - 1.0).abs() < 1e-14);
+            assert!((above.im / v - 1.0).abs() < 1e-14);
+            let below = Complex64::new(x, -0.0).expint_en(n);
+            assert_eq!(below, above.conj());
+        }
+
+        // the sides of the cut meet the rest of the plane
+        let z = Complex64::new(-3.0, 1e-9);
+        let near = Complex64::new(-3.0, 0.0).expint_e1();
+        assert!(close_to_tol(z.expint_e1(), near, 1e-7));
+        assert!(close_to_tol(z.conj().expint_e1(), near.conj(), 1e-7));
+    }
+
+    #[test]
+    fn test_expint_ei() {
+        let cases: [((f64, f64), (f64, f64)); 8] = [
+            ((2.0, 0.0), (4.95423435600189, 0.0)),
+            ((50.0, 0.0), (1.058563689713169e20, 0.0)),
+            ((0.01, 0.0), (-4.017929465426669, 0.0)),
+            ((-3.0, 0.0), (-0.013048381094197037, f64::consts::PI)),
+            ((1.0, 1.0), (1.764625985563854, 2.3877698515105226)),
+            ((-4.0, -2.0), (0.0025563623519437645, -3.1392422912698272)),
+            ((10.0, -30.0), (-633.946547999647, 299.8238666695354)),
+            ((45.0, 1.0), (4.441906596932021e17, 6.583507939483982e17)),
+        ];
+        for &((x, y), (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 1e-14 * expected.norm();
+            assert!(close_to_tol(
+                Complex64::new(x, y).expint_ei(),
+                expected,
+                tol
+            ));
+        }
+        assert_eq!(Complex64::new(2.0, 0.0).expint_ei().im, 0.0);
+        let ei = Complex64::new(-3.0, -0.0).expint_ei();
+        assert!(close_to_tol(
+            ei,
+            Complex64::new(-0.013048381094197037, -f64::consts::PI),
+            1e-15
+        ));
+    }
+
+    #[test]
+    fn test_sin_cos_integral() {
+        let cases: [TrigCase; 10] = [
+            (
+                (0.5, 0.0),
+                (0.4931074180430667, 0.0),
+                (-0.1777840788066129, 0.0),
+            ),
+            (
+                (3.0, 0.0),
+                (1.8486525279994683, 0.0),
+                (0.11962978600800032, 0.0),
+            ),
+            (
+                (20.0, 0.0),
+                (1.54824170104344, 0.0),
+                (0.044419820845353314, 0.0),
+            ),
+            (
+                (-7.0, 0.0),
+                (-1.4545966142480935, 0.0),
+                (0.07669527848218452, f64::consts::PI),
+            ),
+            (
+                (1.0, 2.0),
+                (1.6782404878293682, 2.039684554602206),
+                (2.0302963932917217, -0.15190715517585687),
+            ),
+            (
+                (-2.5, 0.5),
+                (-1.8310917777298512, 0.1177202966666824),
+                (0.29912435887648847, 3.3103314389115526),
+            ),
+            (
+                (10.0, -3.0),
+                (2.2730437014610776, 0.6792894078577161),
+                (-0.680207056960985, 0.6977399239725398),
+            ),
+            (
+                (0.1, -40.0),
+                (293765326262238.75, -3005526723485149.0),
+                (3005526723485149.0, 293765326262237.2),
+            ),
+            (
+                (-30.0, 15.0),
+                (27483.183767677616, -40976.58576605062),
+                (-40976.58576605912, -27481.612971353774),
+            ),
+            (
+                (1e-5, 1e-5),
+                (1.0000000000111111e-05, 9.99999999988889e-06),
+                (-10.589136209788723, 0.7853981633474483),
+            ),
+        ];
+        for &((x, y), (a, b), (c, d)) in cases.iter() {
+            let z = Complex64::new(x, y);
+            let si = Complex64::new(a, b);
+            assert!(close_to_tol(z.sin_integral(), si, 1e-14 * si.norm()));
+            let ci = Complex64::new(c, d);
+            assert!(close_to_tol(z.cos_integral(), ci, 1e-14 * ci.norm()));
+        }
+
+        // Si is odd, and Ci(-z) = Ci(z) ∓ iπ
+        for &z in all_consts.iter().skip(1) {
+            assert_eq!((-z).sin_integral(), -z.sin_integral());
+            let pi = if z.im.is_sign_negative() {
+                f64::consts::PI
+            } else {
+                -f64::consts::PI
+            };
+            let expected = z.cos_integral() + Complex64::new(0.0, pi);
+            assert!(close_to_tol((-z).cos_integral(), expected, 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_expint_special() {
+        let zero = Complex64::new(0.0, 0.0);
+        assert_eq!(zero.expint_e1(), Complex64::new(f64::INFINITY, -0.0));
+        assert_eq!(zero.expint_en(3), Complex64::new(0.5, 0.0));
+        assert!(zero.expint_en(0).re == f64::INFINITY);
+        assert_eq!(zero.sin_integral(), zero);
+        assert_eq!(zero.cos_integral().re, f64::NEG_INFINITY);
+        assert_eq!(zero.expint_ei().re, f64::NEG_INFINITY);
+
+        let inf = Complex64::new(f64::INFINITY, 0.0);
+        assert_eq!(inf.expint_en(2), zero);
+        assert_eq!(
+            inf.sin_integral(),
+            Complex64::new(f64::consts::FRAC_PI_2, 0.0)
+        );
+        assert_eq!(
+            (-inf).sin_integral(),
+            Complex64::new(-f64::consts::FRAC_PI_2, 0.0)
+        );
+        assert_eq!(inf.cos_integral(), zero);
+        assert!(Complex64::new(f64::NAN, 1.0).expint_e1().is_nan());
+        assert!(Complex64::new(1.0, f64::INFINITY).sin_integral().is_nan());
+    }
+}
@@ -0,0 +1,280 @@
+//! Fixed-point arithmetic for `Complex<i16>` and `Complex<i32>` as Q15 and
+//! Q31 values, that is, integers scaled by `2⁻¹⁵` and `2⁻³¹`, in `[-1, 1)`.
+//!
+//! Everything here is integer arithmetic, except the conversions to and
+//! from `Complex<f32>`.
+
+use crate::Complex;
+
+macro_rules! fixed_point {
+    ($($t:ident, $wide:ident, $frac:expr, $q:literal, $acc:literal, $room:literal;)*) => {$(
+        impl Complex<$t> {
+            #[doc = concat!("Multiplies two ", $q, " values, rounding the exact product to nearest,")]
+            /// ties to even, and saturating each part to the range of the
+            /// type.  In particular, `(-1) * (-1)` gives the largest value
+            /// below one.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use num_complex::Complex;
+            ///
+            #[doc = concat!("let half = ", stringify!($t), "::MAX / 2 + 1;")]
+            /// let z = Complex::new(half, half);
+            /// assert_eq!(z.mul_q(&z), Complex::new(0, half));
+            ///
+            #[doc = concat!("let min = Complex::new(", stringify!($t), "::MIN, 0);")]
+            #[doc = concat!("assert_eq!(min.mul_q(&min), Complex::new(", stringify!($t), "::MAX, 0));")]
+            /// ```
+            #[inline]
+            pub fn mul_q(&self, other: &Self) -> Self {
+                Self::from_acc_q(self.mul_acc_q(other, Complex::new(0, 0)))
+            }
+
+            #[doc = concat!("Adds the exact product of `self` and `other` to `acc` in ", $acc, ",")]
+            /// saturating on overflow.
+            ///
+            #[doc = concat!("The accumulator holds `", $room, "` full-scale products before it can")]
+            /// overflow.  Use [`from_acc_q`](Self::from_acc_q) to round
+            /// the sum back.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use num_complex::Complex;
+            ///
+            #[doc = concat!("let x = [Complex::new(", stringify!($t), "::MAX, 0); 4];")]
+            #[doc = concat!("let y = [Complex::new(", stringify!($t), "::MAX / 2, 1); 4];")]
+            /// let acc = x.iter().zip(&y).fold(Complex::new(0, 0), |acc, (x, y)| x.mul_acc_q(y, acc));
+            #[doc = concat!("assert_eq!(Complex::<", stringify!($t), ">::from_acc_q(acc), Complex::new(", stringify!($t), "::MAX, 4));")]
+            /// ```
+            #[inline]
+            pub fn mul_acc_q(&self, other: &Self, acc: Complex<$wide>) -> Complex<$wide> {
+                let (a, b) = ($wide::from(self.re), $wide::from(self.im));
+                let (c, d) = ($wide::from(other.re), $wide::from(other.im));
+                // Each part of the product is exact in the wide type, so only
+                // the sum with `acc` can saturate.
+                Complex::new(
+                    acc.re.saturating_add(a * c - b * d),
+                    acc.im.saturating_add(a * d + b * c),
+                )
+            }
+
+            #[doc = concat!("Rounds an accumulator from [`mul_acc_q`](Self::mul_acc_q) to ", $q, ",")]
+            /// to nearest with ties to even, saturating each part.
+            #[inline]
+            pub fn from_acc_q(acc: Complex<$wide>) -> Self {
+                let round = |x: $wide| {
+                    let (q, r) = (x >> $frac, x & ((1 << $frac) - 1));
+                    let half = 1 << ($frac - 1);
+                    let q = if r > half || (r == half && q & 1 == 1) { q + 1 } else { q };
+                    <$t>::try_from(q).unwrap_or(if q > 0 { <$t>::MAX } else { <$t>::MIN })
+                };
+                Complex::new(round(acc.re), round(acc.im))
+            }
+
+            #[doc = concat!("Approximates `|self|` in ", $q, " as `max(M, 7M/8 + m/2)`, where `M` and `m`")]
+            /// are the larger and smaller of `|re|` and `|im|`, saturating at
+            /// the largest value below one.
+            ///
+            /// The result is within -3.0% and +0.8% of the true magnitude,
+            /// before truncation to the last bit.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use num_complex::Complex;
+            ///
+            #[doc = concat!("let z = Complex::new(", stringify!($t), "::MAX / 5 * 3, ", stringify!($t), "::MAX / 5 * 4);")]
+            #[doc = concat!("let err = (z.norm_approx_q() - ", stringify!($t), "::MAX / 5 * 5) as f64 / ", stringify!($t), "::MAX as f64;")]
+            /// assert!(-0.03 < err && err < 0.008);
+            /// ```
+            #[inline]
+            pub fn norm_approx_q(&self) -> $t {
+                let (re, im) = ($wide::from(self.re).abs(), $wide::from(self.im).abs());
+                let (max, min) = if re > im { (re, im) } else { (im, re) };
+                let norm = max.max(max - (max >> 3) + (min >> 1));
+                <$t>::try_from(norm).unwrap_or(<$t>::MAX)
+            }
+
+            #[doc = concat!("Converts from ", $q, " to `Complex<f32>`, exactly if the value fits in")]
+            /// the 24-bit significand, and otherwise rounded to nearest.
+            #[inline]
+            pub fn to_f32_q(&self) -> Complex<f32> {
+                const SCALE: f32 = 1.0 / (1u64 << $frac) as f32;
+                Complex::new(self.re as f32 * SCALE, self.im as f32 * SCALE)
+            }
+
+            #[doc = concat!("Converts from `Complex<f32>` to ", $q, ", rounding to nearest with ties")]
+            /// to even, and saturating each part.  NaN converts to zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use num_complex::Complex;
+            ///
+            #[doc = concat!("let z = Complex::<", stringify!($t), ">::from_f32_q(Complex::new(0.25, -2.0));")]
+            #[doc = concat!("assert_eq!(z, Complex::new(", stringify!($t), "::MAX / 4 + 1, ", stringify!($t), "::MIN));")]
+            /// assert_eq!(z.to_f32_q(), Complex::new(0.25, -1.0));
+            /// ```
+            #[inline]
+            pub fn from_f32_q(z: Complex<f32>) -> Self {
+                const SCALE: f32 = (1u64 << $frac) as f32;
+                let convert = |x: f32| {
+                    // Scaling is exact, and so is the fraction after truncation.
+                    let y = x * SCALE;
+                    let t = y as $wide;
+                    let frac = y - t as f32;
+                    let odd = t & 1 == 1;
+                    let t = if frac > 0.5 || (frac == 0.5 && odd) {
+                        t.saturating_add(1)
+                    } else if frac < -0.5 || (frac == -0.5 && odd) {
+                        t.saturating_sub(1)
+                    } else {
+                        t
+                    };
+                    <$t>::try_from(t).unwrap_or(if t > 0 { <$t>::MAX } else { <$t>::MIN })
+                };
+                Complex::new(convert(z.re), convert(z.im))
+            }
+        }
+    )*};
+}
+
+fixed_point! {
+    i16, i64, 15, "Q15", "Q30", "2^32";
+    i32, i128, 31, "Q31", "Q62", "2^64";
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Complex;
+
+    const VALUES: [i16; 12] = [
+        i16::MIN,
+        -32767,
+        -16385,
+        -16384,
+        -3,
+        -1,
+        0,
+        1,
+        2,
+        16384,
+        23170,
+        i16::MAX,
+    ];
+
+    fn complexes() -> impl Iterator<Item = Complex<i16>> {
+        VALUES
+            .iter()
+            .flat_map(|&a| VALUES.iter().map(move |&b| Complex::new(a, b)))
+    }
+
+    // Reference rounding by comparing twice the remainder with the divisor.
+    fn round_q15(x: i64) -> i16 {
+        let (q, r) = (x.div_euclid(1 << 15), x.rem_euclid(1 << 15));
+        let q = match (2 * r).cmp(&(1 << 15)) {
+            core::cmp::Ordering::Greater => q + 1,
+            core::cmp::Ordering::Equal => q + (q & 1),
+            core::cmp::Ordering::Less => q,
+        };
+        q.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+    }
+
+    #[test]
+    fn test_mul_q15() {
+        for x in complexes() {
+            for y in complexes() {
+                let exact = Complex::new(i64::from(x.re), i64::from(x.im))
+                    * Complex::new(i64::from(y.re), i64::from(y.im));
+                let expected = Complex::new(round_q15(exact.re), round_q15(exact.im));
+                assert_eq!(x.mul_q(&y), expected, "{} * {}", x, y);
+                assert_eq!(x.mul_acc_q(&y, Complex::new(0, 0)), exact);
+            }
+        }
+
+        // ties to even, either side of zero
+        let half = Complex::new(1i16 << 14, 0);
+        let expected = [-2, -2, -1, 0, 0, 0, 1, 2];
+        for (n, &e) in (-4i16..4).zip(&expected) {
+            assert_eq!(Complex::new(n, 0).mul_q(&half).re, e, "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_mul_acc_q31() {
+        let max = Complex::new(i32::MAX, i32::MAX);
+        let mut acc = Complex::new(0, 0);
+        for _ in 0..8 {
+            acc = max.mul_acc_q(&max, acc);
+        }
+        assert_eq!(acc.re, 0);
+        assert_eq!(acc.im, 16 * i128::from(i32::MAX).pow(2));
+        assert_eq!(Complex::<i32>::from_acc_q(acc), Complex::new(0, i32::MAX));
+
+        let acc = Complex::new(i128::MAX, i128::MIN);
+        let z = Complex::new(1i32, -1).mul_acc_q(&Complex::new(1, 0), acc);
+        assert_eq!(z, acc);
+        assert_eq!(
+            Complex::<i32>::from_acc_q(z),
+            Complex::new(i32::MAX, i32::MIN)
+        );
+        assert_eq!(
+            Complex::<i32>::from_acc_q(Complex::new(3 << 30, -(5 << 29))),
+            Complex::new(2, -1)
+        );
+
+        // the real part cancels before it reaches the accumulator
+        let acc = Complex::new(i128::MAX - 1, 0);
+        assert_eq!(
+            max.mul_acc_q(&max, acc),
+            Complex::new(i128::MAX - 1, 2 * i128::from(i32::MAX).pow(2))
+        );
+        let acc = Complex::new(i128::MIN + 1, i128::MAX - 1);
+        assert_eq!(
+            max.mul_acc_q(&max, acc),
+            Complex::new(i128::MIN + 1, i128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_norm_approx_q() {
+        for z in complexes() {
+            let exact = (f64::from(z.re).hypot(f64::from(z.im))).min(f64::from(i16::MAX));
+            let approx = f64::from(z.norm_approx_q());
+            assert!(approx <= exact * 1.008 + 1.0, "{}", z);
+            assert!(approx >= exact * 0.97 - 1.0, "{}", z);
+        }
+        let min = Complex::new(i32::MIN, i32::MIN);
+        assert_eq!(min.norm_approx_q(), i32::MAX);
+        assert_eq!(Complex::new(0, i32::MIN + 1).norm_approx_q(), i32::MAX);
+    }
+
+    #[test]
+    fn test_f32() {
+        for z in complexes() {
+            assert_eq!(Complex::<i16>::from_f32_q(z.to_f32_q()), z);
+        }
+        let q31 = |re: f32, im: f32| Complex::<i32>::from_f32_q(Complex::new(re, im));
+        assert_eq!(q31(1.0, -1.0), Complex::new(i32::MAX, i32::MIN));
+        assert_eq!(
+            q31(f32::INFINITY, f32::NEG_INFINITY),
+            Complex::new(i32::MAX, i32::MIN)
+        );
+        assert_eq!(q31(f32::NAN, -0.0), Complex::new(0, 0));
+        assert_eq!(q31(0.5, -0.75), Complex::new(1 << 30, -(3 << 29)));
+        let z = Complex::new(i32::MAX - 63, i32::MIN + 1);
+        assert_eq!(z.to_f32_q(), Complex::new(1.0, -1.0));
+
+        // ties to even, and the rounding that goes wrong with `+ 0.5`
+        let q15 = |re: f32| Complex::<i16>::from_f32_q(Complex::new(re, 0.0)).re;
+        let lsb = 1.0 / 32768.0;
+        assert_eq!(q15(2.5 * lsb), 2);
+        assert_eq!(q15(3.5 * lsb), 4);
+        assert_eq!(q15(-2.5 * lsb), -2);
+        assert_eq!(q15(-3.5 * lsb), -4);
+        assert_eq!(q15(0.49999997 * lsb), 0);
+        assert_eq!(q15(-0.50000006 * lsb), -1);
+    }
+}
@@ -0,0 +1,466 @@
+//! The gamma function and its logarithmic derivative.
+//!
+//! `ln_gamma` follows D. E. G. Hare, "Computing the Principal Branch of
+//! log-Gamma", J. Algorithms 25(2), 1997: Stirling's series away from the
+//! origin, Taylor series around the zeros at 1 and 2, the recurrence in
+//! between, and the reflection formula in the left half-plane, keeping count
+//! of the windings so the result is analytic off the negative real axis.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::{constant, horner, Complex};
+
+/// Stirling's series is used when the real or imaginary part is beyond this.
+const STIRLING_MIN: f64 = 7.0;
+
+/// The Taylor series around 1 and 2 are used within this distance.
+const TAYLOR_RADIUS: f64 = 0.2;
+
+/// `B_2k / (2k (2k - 1))`, for `k` from 8 down to 1.
+const STIRLING: [f64; 8] = [
+    -0.029550653594771242,
+    0.00641025641025641,
+    -0.0019175269175269176,
+    0.0008417508417508417,
+    -0.0005952380952380953,
+    0.0007936507936507937,
+    -0.002777777777777778,
+    0.08333333333333333,
+];
+
+/// `B_2k / 2k`, for `k` from 8 down to 1.
+const DIGAMMA_ASYMPTOTIC: [f64; 8] = [
+    -0.4432598039215686,
+    0.08333333333333333,
+    -0.021092796092796094,
+    0.007575757575757576,
+    -0.004166666666666667,
+    0.003968253968253968,
+    -0.008333333333333333,
+    0.08333333333333333,
+];
+
+/// Taylor coefficients of `ln Γ(1 + w)`: `(-1)^k ζ(k) / k`, for `k` from 25
+/// down to 2, and then `-γ`.
+const LN_GAMMA_TAYLOR: [f64; 25] = [
+    -0.04000000119214014,
+    0.04166666915034121,
+    -0.04347826605304026,
+    0.04545455629320467,
+    -0.047619070330142226,
+    0.05000004769810169,
+    -0.05263167937961666,
+    0.055555767627403614,
+    -0.058823978658684585,
+    0.06250095514121304,
+    -0.06666870588242046,
+    0.07143294629536133,
+    -0.0769325164113522,
+    0.083353840546109,
+    -0.09095401714582904,
+    0.1000994575127818,
+    -0.11133426586956469,
+    0.12550966952474304,
+    -0.1440498967688461,
+    0.1695571769974082,
+    -0.20738555102867398,
+    0.27058080842778454,
+    -0.40068563438653143,
+    0.8224670334241132,
+    -0.5772156649015329,
+];
+
+/// The positive zero of the digamma function, split into two parts.
+const DIGAMMA_ROOT: (f64, f64) = (1.4616321449683622, 9.549995429965697e-17);
+
+/// The Taylor series around the positive zero is used within this distance.
+const DIGAMMA_ROOT_RADIUS: f64 = 0.25;
+
+/// Taylor coefficients of `ψ` around its positive zero, `ψ⁽ⁿ⁾(x₀) / n!`, for
+/// `n` from 23 down to 1.
+const DIGAMMA_ROOT_TAYLOR: [f64; 23] = [
+    0.0001106337276874741,
+    -0.00016170622091974803,
+    0.00023635601564027053,
+    -0.0003454680251063077,
+    0.000504953265834602,
+    -0.0007380709389960052,
+    0.0010788252019162967,
+    -0.0015769367714301972,
+    0.002305126326734928,
+    -0.003369801655439328,
+    0.004926781395729853,
+    -0.007204534386356869,
+    0.010538791616612175,
+    -0.01542476590494896,
+    0.022597648232218104,
+    -0.03316112647484736,
+    0.04880428816414311,
+    -0.07219956125645471,
+    0.10782405069126237,
+    -0.16394270544240652,
+    0.258499760955651,
+    -0.4427631689835921,
+    0.9676722454476212,
+];
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the gamma function `Γ(self)`.
+    ///
+    /// This is `exp(ln_gamma(self))`, except that real arguments give real
+    /// results, and small positive integers give exact factorials.  The
+    /// relative error of the `f64` result is about `1e-14` for small
+    /// arguments, and grows in proportion to `|self| ln|self|` for large ones,
+    /// which is the function's own sensitivity to rounding `self`.
+    ///
+    /// At the poles, the non-positive integers, this returns an infinite real
+    /// part with a NaN imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// assert_eq!(Complex64::new(5.0, 0.0).gamma(), Complex64::new(24.0, 0.0));
+    ///
+    /// // Γ(1/2) = √π
+    /// let z = Complex64::new(0.5, 0.0).gamma();
+    /// assert!((z.re - std::f64::consts::PI.sqrt()).abs() < 1e-14);
+    /// ```
+    #[inline]
+    pub fn gamma(self) -> Self {
+        let Complex { re, im } = self;
+        if !(im.is_zero() && re.is_finite()) || is_pole(self) {
+            return self.ln_gamma().exp();
+        }
+        if re >= T::one() && re <= constant(30.0) && re == re.floor() {
+            let mut k = T::one();
+            let mut acc = T::one();
+            while k < re {
+                acc = acc * k;
+                k = k + T::one();
+            }
+            return Self::new(acc, T::zero());
+        }
+        let abs = self.ln_gamma().re.exp();
+        Self::new(if gamma_is_negative(re) { -abs } else { abs }, T::zero())
+    }
+
+    /// Computes the principal branch of the log-gamma function, `ln Γ(self)`.
+    ///
+    /// This is the analytic continuation of the real `ln Γ(x)` for `x > 0`,
+    /// with a branch cut along the negative real axis, so it generally differs
+    /// from `gamma(self).ln()` by a multiple of `2πi`.  Its real part is always
+    /// `ln|Γ(self)|`, and `ln_gamma(self + 1) = ln_gamma(self) + ln(self)`
+    /// holds exactly rather than modulo `2πi`.  On the cut, the sign of a zero
+    /// imaginary part selects the side.
+    ///
+    /// The error of the `f64` result is within about `1e-14` times the larger
+    /// of `|ln_gamma(self)|` and `1`.  At the poles, the non-positive integers,
+    /// this returns `+∞` with a NaN imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // On the cut, Γ(-5/2) = -8√π/15 is negative, and the imaginary part is -3π.
+    /// let z = Complex64::new(-2.5, 0.0).ln_gamma();
+    /// let expected = (8.0 * std::f64::consts::PI.sqrt() / 15.0).ln();
+    /// assert!((z.re - expected).abs() < 1e-14);
+    /// assert!((z.im + 3.0 * std::f64::consts::PI).abs() < 1e-15);
+    /// ```
+    pub fn ln_gamma(self) -> Self {
+        let Complex { re, im } = self;
+        let one = T::one();
+        if !(re.is_finite() && im.is_finite()) {
+            return if re == T::infinity() && !im.is_nan() {
+                Self::new(re, im)
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if is_pole(self) {
+            return Self::new(T::infinity(), T::nan());
+        }
+        let small = constant(STIRLING_MIN);
+        let radius = constant(TAYLOR_RADIUS);
+        if re < constant(0.1) {
+            // Hare's reflection formula, Proposition 3.1:
+            // ln Γ(z) = ln π - ln sin(πz) - ln Γ(1 - z) + 2πi sgn(y) ⌊x/2 + 1/4⌋
+            let two = one + one;
+            let quarter = one / (two + two);
+            let turns = (re / two + quarter).floor();
+            let winding = copysign_pi(two * turns, im);
+            Self::new(T::PI().ln(), winding) - ln_sin_pi(self) - (Self::one() - self).ln_gamma()
+        } else if re > small || im.abs() > small {
+            ln_gamma_stirling(self)
+        } else if (self - Self::one()).norm() <= radius {
+            ln_gamma_taylor(self - Self::one())
+        } else if (self - Self::new(one + one, T::zero())).norm() <= radius {
+            let w = self - Self::one();
+            w.ln() + ln_gamma_taylor(w - Self::one())
+        } else if im.is_sign_positive() {
+            ln_gamma_recurrence(self)
+        } else {
+            ln_gamma_recurrence(self.conj()).conj()
+        }
+    }
+
+    /// Computes the digamma function, `ψ(self) = Γ'(self) / Γ(self)`.
+    ///
+    /// The `f64` result is within about `1e-14` relative error, or absolute
+    /// error near the zeros on the negative real axis.  At the poles, the
+    /// non-positive integers, this returns an infinite real part with a NaN
+    /// imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // ψ(1) = -γ
+    /// let z = Complex64::new(1.0, 0.0).digamma();
+    /// assert!((z.re + 0.5772156649015329).abs() < 1e-15);
+    /// ```
+    pub fn digamma(self) -> Self {
+        let Complex { re, im } = self;
+        let one = T::one();
+        let half = one / (one + one);
+        if !(re.is_finite() && im.is_finite()) {
+            return if re == T::infinity() && !im.is_nan() {
+                Self::new(re, im)
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if is_pole(self) {
+            return Self::new(T::infinity(), T::nan());
+        }
+        if re < half {
+            // ψ(z) = ψ(1 - z) - π cot(πz), where cot(πz) has period 1.
+            let n = re.round();
+            let cot = Self::new(re - n, im).scale(T::PI()).tan().finv();
+            return (Self::one() - self).digamma() - cot.scale(T::PI());
+        }
+        // The root, as the sum of two `T`s.
+        let (root_hi, root_lo) = DIGAMMA_ROOT;
+        let hi = constant::<T>(root_hi);
+        let lo = constant::<T>(root_hi - hi.to_f64().unwrap() + root_lo);
+        let w = Self::new(re - hi - lo, im);
+        if w.norm() <= constant(DIGAMMA_ROOT_RADIUS) {
+            return horner(&DIGAMMA_ROOT_TAYLOR, w) * w;
+        }
+        // ψ(z) = ψ(z + n) - Σ 1/(z + k), for k from 0 to n - 1
+        let small = constant(STIRLING_MIN + 3.0);
+        let mut z = self;
+        let mut acc = Self::zero();
+        while z.re <= small && z.im.abs() <= small {
+            acc = acc + z.finv();
+            z = z + Self::one();
+        }
+        let r = z.finv();
+        let asymptotic = z.ln() - r.scale(half) - horner(&DIGAMMA_ASYMPTOTIC, r * r) * r * r;
+        asymptotic - acc
+    }
+}
+
+/// Returns whether `z` is one of the poles of `Γ`, at the non-positive integers.
+#[inline]
+pub(crate) fn is_pole<T: Float>(z: Complex<T>) -> bool {
+    z.im.is_zero() && z.re <= T::zero() && z.re == z.re.floor()
+}
+
+/// Returns whether `Γ(x)` is negative, for finite `x` that isn't a pole.
+#[inline]
+pub(crate) fn gamma_is_negative<T: Float>(x: T) -> bool {
+    let two = T::one() + T::one();
+    x < T::zero() && x.floor() - two * (x.floor() / two).floor() != T::zero()
+}
+
+/// Returns `πx` with the sign of `y`.
+#[inline]
+fn copysign_pi<T: Float + FloatConst>(x: T, y: T) -> T {
+    if y.is_sign_negative() {
+        -T::PI() * x
+    } else {
+        T::PI() * x
+    }
+}
+
+/// Stirling's series: `(z - 1/2) ln z - z + ln(2π)/2 + Σ B_2k / (2k(2k-1) z^(2k-1))`.
+#[inline]
+fn ln_gamma_stirling<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let one = T::one();
+    let half = one / (one + one);
+    let ln_sqrt_2pi = (T::PI() + T::PI()).ln() * half;
+    let r = z.finv();
+    let series = horner(&STIRLING, r * r) * r;
+    (z - half) * z.ln() - z + ln_sqrt_2pi + series
+}
+
+/// The Taylor series of `ln Γ(1 + w)`, for small `w`.
+#[inline]
+fn ln_gamma_taylor<T: Float>(w: Complex<T>) -> Complex<T> {
+    horner(&LN_GAMMA_TAYLOR, w) * w
+}
+
+/// Applies `ln Γ(z) = ln Γ(z + n) - ln(z (z + 1) ... (z + n - 1))` until the
+/// real part is large enough for Stirling's series, for `Im(z) ≥ 0`.
+///
+/// The logarithm of the product is taken at the end, so each time the product
+/// crosses the negative real axis from above, `2πi` is subtracted to stay on
+/// the principal branch.
+fn ln_gamma_recurrence<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let small = constant(STIRLING_MIN);
+    let mut product = z;
+    let mut z = z + Complex::one();
+    let mut below = false;
+    let mut crossings = T::zero();
+    while z.re <= small {
+        product = product * z;
+        let now_below = product.im.is_sign_negative();
+        if now_below && !below {
+            crossings = crossings + T::one();
+        }
+        below = now_below;
+        z = z + Complex::one();
+    }
+    let winding = Complex::new(T::zero(), (T::PI() + T::PI()) * crossings);
+    ln_gamma_stirling(z) - product.ln() - winding
+}
+
+/// Returns the principal value of `ln sin(πz)`, without overflow.
+pub(crate) fn ln_sin_pi<T: Float + FloatConst>(z: Complex<T>) -> Complex<T> {
+    let one = T::one();
+    let two = one + one;
+    // sin(πz) has period 2, and this subtraction is exact.
+    let z = Complex::new(z.re - two * (z.re / two).round(), z.im);
+    if z.im.abs() <= one {
+        return z.sin_pi().ln();
+    }
+    if z.im.is_sign_negative() {
+        return ln_sin_pi(z.conj()).conj();
+    }
+    // With y > 1, e^(2πiz) is small, and
+    // sin(πz) = e^(-πiz) (1 - e^(2πiz)) i/2
+    let pi = T::PI();
+    let tail = (-z.scale(two).exp_i_pi()).ln_1p();
+    let re = pi * z.im - two.ln() + tail.re;
+    let im = pi * (one / two - z.re) + tail.im;
+    let im = if im > pi { im - (pi + pi) } else { im };
+    Complex::new(re, im)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::test::{_0_0i, _1_0i};
+    use crate::{Complex, Complex64};
+    use std::f64::consts::PI;
+
+    type Case = ((f64, f64), (f64, f64));
+
+    #[test]
+    fn test_gamma() {
+        let mut factorial = 1.0;
+        for n in 1..=30 {
+            assert_eq!(Complex::new(f64::from(n), 0.0).gamma().re, factorial);
+            factorial *= f64::from(n);
+        }
+        // Γ(1/2 - n) = (-4)^n n! √π / (2n)!
+        let z = Complex64::new(-1.5, 0.0).gamma();
+        assert!((z.re / (4.0 * PI.sqrt() / 3.0) - 1.0).abs() < 1e-14);
+        assert_eq!(z.im, 0.0);
+        let z = Complex64::new(-2.5, 0.0).gamma();
+        assert!((z.re / (-8.0 * PI.sqrt() / 15.0) - 1.0).abs() < 1e-14);
+
+        // Γ(1 + i), from mpmath
+        let z = Complex::new(1.0, 1.0).gamma();
+        let expected = Complex::new(0.49801566811835607, -0.15494982830181067);
+        assert!(close_to_tol(z, expected, 1e-14));
+        // |Γ(iy)|² = π / (y sinh(πy))
+        for &y in [0.5, 3.0, 20.0, 100.0].iter() {
+            let z = Complex::new(0.0, y).gamma();
+            let expected = PI / (y * (PI * y).sinh());
+            assert!((z.norm_sqr() / expected - 1.0).abs() < 1e-12);
+        }
+
+        assert_eq!(Complex::new(172.0, 0.0).gamma().re, f64::INFINITY);
+        assert_eq!(Complex64::new(-200.5, 0.0).gamma().re.abs(), 0.0);
+        for &x in [0.0, -1.0, -20.0].iter() {
+            let z = Complex64::new(x, 0.0).gamma();
+            assert!(z.re.is_infinite() && z.im.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        // from mpmath's loggamma
+        let cases: [Case; 10] = [
+            ((0.5, 0.0), (0.5723649429247001, 0.0)),
+            ((1.2, 0.1), (-0.09169751241265475, -0.028658497320683367)),
+            ((2.1, -0.1), (0.04240520033440983, -0.04859334282511769)),
+            ((3.0, 4.0), (-1.7566267846037842, 4.742664438034658)),
+            ((-0.5, 0.5), (0.4589608330895958, -3.1069236923143957)),
+            ((-3.7, 1e-3), (-1.3797473261447153, -12.567215679735883)),
+            ((-3.7, -1e-3), (-1.3797473261447153, 12.567215679735883)),
+            ((0.1, 30.0), (-47.56542355569917, 71.40632506346213)),
+            ((-100.5, 2.0), (-370.4712093987986, -308.0704781223277)),
+            ((1e10, 1e10), (215870263557.46252, 231578226835.78607)),
+        ];
+        for &((x, y), (u, v)) in cases.iter() {
+            let expected = Complex::new(u, v);
+            let tol = 1e-14 * expected.norm().max(1.0);
+            assert!(close_to_tol(Complex::new(x, y).ln_gamma(), expected, tol));
+        }
+
+        // The recurrence holds exactly, without any 2πi jumps.
+        let mut z = Complex::new(-6.5, 0.75);
+        for _ in 0..15 {
+            let lhs = (z + 1.0).ln_gamma();
+            let rhs = z.ln_gamma() + z.ln();
+            assert!(close_to_tol(lhs, rhs, 1e-12));
+            z += 1.0;
+        }
+
+        assert_eq!(_1_0i.ln_gamma(), _0_0i);
+        assert_eq!(Complex::new(2.0, 0.0).ln_gamma(), _0_0i);
+        let z = Complex::new(-2.0, 0.0).ln_gamma();
+        assert!(z.re == f64::INFINITY && z.im.is_nan());
+        let inf = Complex::new(f64::INFINITY, 0.0);
+        assert_eq!(inf.ln_gamma(), inf);
+        assert!(Complex::new(f64::NAN, 0.0).ln_gamma().is_nan());
+    }
+
+    #[test]
+    fn test_digamma() {
+        // from mpmath's digamma
+        let cases: [Case; 8] = [
+            ((1.0, 0.0), (-0.5772156649015329, 0.0)),
+            ((1.5, 0.0), (0.03648997397857652, 0.0)),
+            ((1.4, 0.1), (-0.05645897664782347, 0.10223366466424555)),
+            ((0.3, 2.0), (0.687523593749104, 1.6727302110566287)),
+            ((-2.5, 0.5), (1.1165080219699073, 2.7175825969005913)),
+            ((-0.5, 0.0), (0.03648997397857652, 0.0)),
+            ((5.0, -12.0), (2.5505036650829576, -1.211858585808474)),
+            ((-1e5, 1e5), (11.859501555250201, 2.3561969901881783)),
+        ];
+        for &((x, y), (u, v)) in cases.iter() {
+            let expected = Complex::new(u, v);
+            let tol = 1e-14 * expected.norm().max(1.0);
+            assert!(close_to_tol(Complex::new(x, y).digamma(), expected, tol));
+        }
+
+        // ψ(z + 1) = ψ(z) + 1/z
+        let z = Complex::new(-3.3, 0.25);
+        assert!(close_to_tol(
+            (z + 1.0).digamma(),
+            z.digamma() + z.inv(),
+            1e-13
+        ));
+
+        let z = Complex64::new(-3.0, 0.0).digamma();
+        assert!(z.re.is_infinite() && z.im.is_nan());
+    }
+}
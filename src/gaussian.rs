@@ -0,0 +1,832 @@
+//! Euclidean division and number theory of the Gaussian integers `ℤ[i]`, for
+//! `Complex<T>` with an integer type `T`, such as the primitive signed
+//! integers or a big integer.
+//!
+//! As with [`norm_sqr`](Complex::norm_sqr), intermediate products of the
+//! components must fit in `T`, so for primitive integers these overflow for
+//! components beyond about the square root of `T::MAX`.
+//!
+//! Everything here works without the `std` feature, except
+//! [`factorize`](Complex::factorize), which returns its factors in a `Vec`.
+//! Without `std`, [`factorize_with`](Complex::factorize_with) gives the same
+//! factors through a callback.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use num_traits::{
+    CheckedAdd, CheckedEuclid, CheckedMul, CheckedSub, Euclid, Num, One, Signed, Zero,
+};
+
+use crate::Complex;
+
+/// Trial division by these settles the primality of any `n < 41²`, and as
+/// Miller–Rabin bases they are deterministic for `n < 3.18 × 10²³`.
+const SMALL_PRIMES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+impl<T: Clone + Num + Signed + Ord> Complex<T> {
+    /// Returns the Gaussian integer nearest to `self / divisor`, rounding
+    /// each component to the nearest integer, with ties towards zero.
+    ///
+    /// The remainder, `self - divisor * quotient`, then has at most half the
+    /// norm of the divisor, so this is the quotient of Euclidean division,
+    /// as in [`Euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero, as integer division does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let (a, b) = (Complex::new(7i32, 5), Complex::new(2, 1));
+    /// let q = a.div_round(&b);
+    /// assert_eq!(q, Complex::new(4, 1));
+    /// assert_eq!(a - b * q, Complex::new(0, -1));
+    /// ```
+    pub fn div_round(&self, divisor: &Self) -> Self {
+        let n = divisor.norm_sqr();
+        let z = self * divisor.conj();
+        Self::new(round_div(z.re, &n), round_div(z.im, &n))
+    }
+
+    /// Returns the floor of `self / divisor`, the largest Gaussian integer
+    /// whose components are at most those of the exact quotient.
+    ///
+    /// The remainder, `self - divisor * quotient`, is then `divisor` times a
+    /// number in the unit square `[0, 1)²`, so its norm is less than twice
+    /// the divisor's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero, as integer division does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let (a, b) = (Complex::new(-7i32, 5), Complex::new(2, 1));
+    /// assert_eq!(a.div_floor(&b), Complex::new(-2, 3));
+    /// assert_eq!(a.div_rem(&b).0, Complex::new(-1, 3));
+    /// ```
+    pub fn div_floor(&self, divisor: &Self) -> Self {
+        let n = divisor.norm_sqr();
+        let z = self * divisor.conj();
+        Self::new(floor_div(z.re, &n), floor_div(z.im, &n))
+    }
+
+    /// Returns the greatest common divisor of `self` and `other` as Gaussian
+    /// integers, normalized by a unit to have a positive real part and a
+    /// non-negative imaginary part.  The gcd of zero and zero is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let a = Complex::new(4i64, 10);
+    /// let b = Complex::new(-8i64, 6);
+    /// assert_eq!(a.gcd(&b), Complex::new(2, 0));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.clone() - b.clone() * a.div_round(&b);
+            a = b;
+            b = r;
+        }
+        normalize(a).0
+    }
+
+    /// Returns `(g, x, y)` where `g` is the [`gcd`](Complex::gcd) of `self`
+    /// and `other`, and `x` and `y` are Bézout coefficients with
+    /// `g = x·self + y·other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let a = Complex::new(11i64, 3);
+    /// let b = Complex::new(1i64, 8);
+    /// let (g, x, y) = a.extended_gcd(&b);
+    /// assert_eq!(g, Complex::new(2, 1));
+    /// assert_eq!(x * a + y * b, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let (mut x0, mut x1) = (Self::one(), Self::zero());
+        let (mut y0, mut y1) = (Self::zero(), Self::one());
+        while !b.is_zero() {
+            let q = a.div_round(&b);
+            let r = a - b.clone() * q.clone();
+            a = b;
+            b = r;
+            let x = x0 - x1.clone() * q.clone();
+            x0 = x1;
+            x1 = x;
+            let y = y0 - y1.clone() * q;
+            y0 = y1;
+            y1 = y;
+        }
+        let (g, unit) = normalize(a);
+        (g, x0 * unit.clone(), y0 * unit)
+    }
+
+    /// Returns `true` if `self` is a Gaussian prime.
+    ///
+    /// These are the associates of `1 + i`, of the rational primes `q ≡ 3
+    /// (mod 4)`, and of the Gaussian integers whose norm is a rational prime
+    /// `p ≡ 1 (mod 4)`.  Primality of rational integers is tested by trial
+    /// division and Miller–Rabin, which is deterministic below `3.18 × 10²³`
+    /// and a strong probable-prime test beyond that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// assert!(Complex::new(1i32, 1).is_gaussian_prime());
+    /// assert!(Complex::new(0i32, -7).is_gaussian_prime());
+    /// assert!(Complex::new(2i32, 3).is_gaussian_prime());
+    /// // 5 = (2 + i)(2 - i)
+    /// assert!(!Complex::new(5i32, 0).is_gaussian_prime());
+    /// ```
+    pub fn is_gaussian_prime(&self) -> bool {
+        if self.re.is_zero() || self.im.is_zero() {
+            let n = self.re.abs() + self.im.abs();
+            is_prime(&n) && n % small::<T>(4) == small(3)
+        } else {
+            is_prime(&self.norm_sqr())
+        }
+    }
+
+    /// Returns a representation of `n` as a sum of two squares, as the
+    /// Gaussian integer `a + bi` with `a² + b² = n` and `a ≥ b ≥ 0`, or `None`
+    /// if there is none.
+    ///
+    /// A representation exists when every prime `q ≡ 3 (mod 4)` divides `n`
+    /// to an even power.  This one is the product of a Gaussian prime above
+    /// each prime factor of `n`, so when `n` has several representations,
+    /// the others follow from conjugating some of those primes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// assert_eq!(Complex::sum_of_two_squares(13i64), Some(Complex::new(3, 2)));
+    /// assert_eq!(Complex::sum_of_two_squares(18i64), Some(Complex::new(3, 3)));
+    /// assert_eq!(Complex::sum_of_two_squares(21i64), None);
+    /// ```
+    pub fn sum_of_two_squares(n: T) -> Option<Self> {
+        if n.is_negative() {
+            return None;
+        }
+        if n.is_zero() {
+            return Some(Self::zero());
+        }
+        let mut z = Self::one();
+        let mut m = n;
+        while !m.is_one() {
+            let p = prime_factor(m.clone());
+            let mut e = 0;
+            while (m.clone() % p.clone()).is_zero() {
+                m = m / p.clone();
+                e += 1;
+            }
+            let factor = if p == small(2) {
+                Self::new(T::one(), T::one())
+            } else if p.clone() % small::<T>(4) == small(3) {
+                if e % 2 == 1 {
+                    return None;
+                }
+                e /= 2;
+                Self::from(p)
+            } else {
+                prime_above(p)
+            };
+            z = z * factor.powu(e);
+        }
+        let (a, b) = (z.re.abs(), z.im.abs());
+        Some(if a >= b {
+            Self::new(a, b)
+        } else {
+            Self::new(b, a)
+        })
+    }
+
+    /// Factors `self` into Gaussian primes, returning a unit and the distinct
+    /// primes with their exponents, so that `self` is the unit times the
+    /// product of the primes raised to their exponents.
+    ///
+    /// Each prime is normalized as by [`gcd`](Complex::gcd), to have a
+    /// positive real part and a non-negative imaginary part, and they are
+    /// sorted by norm, then by real part.  The rational factors of the norm
+    /// are found by trial division and Pollard's rho method.  Zero has no
+    /// factorization, and gives `(0, [])`.
+    ///
+    /// This collects the factors into a `Vec`, so it needs the `std` feature;
+    /// [`factorize_with`](Complex::factorize_with) works without it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// // 10i = -i (1 + i)² (1 + 2i) (2 + i)
+    /// let (unit, factors) = Complex::new(0i64, 10).factorize();
+    /// assert_eq!(unit, Complex::new(0, -1));
+    /// assert_eq!(factors, [
+    ///     (Complex::new(1, 1), 2),
+    ///     (Complex::new(1, 2), 1),
+    ///     (Complex::new(2, 1), 1),
+    /// ]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn factorize(&self) -> (Self, Vec<(Self, u32)>) {
+        let mut factors = Vec::new();
+        let unit = self.factorize_with(|pi, k| factors.push((pi, k)));
+        factors.sort_by(|(a, _), (b, _)| {
+            (a.norm_sqr(), a.re.clone()).cmp(&(b.norm_sqr(), b.re.clone()))
+        });
+        (unit, factors)
+    }
+
+    /// Factors `self` into Gaussian primes as [`factorize`](Complex::factorize)
+    /// does, but passes each distinct prime and its exponent to `f` rather
+    /// than collecting them, and returns only the unit.  This needs no
+    /// allocation, so it is available without the `std` feature.
+    ///
+    /// The primes are normalized as for `factorize`, but are not sorted.  The
+    /// ones dividing the same rational prime are passed one after the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// // 10i = -i (1 + i)² (1 + 2i) (2 + i)
+    /// let (mut count, mut product) = (0, Complex::new(1i64, 0));
+    /// let unit = Complex::new(0i64, 10).factorize_with(|p, k| {
+    ///     count += 1;
+    ///     product *= p.powu(k);
+    /// });
+    /// assert_eq!(unit, Complex::new(0, -1));
+    /// assert_eq!(count, 3);
+    /// assert_eq!(unit * product, Complex::new(0, 10));
+    /// ```
+    pub fn factorize_with<F: FnMut(Self, u32)>(&self, mut f: F) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let mut z = self.clone();
+        let mut m = self.norm_sqr();
+        while !m.is_one() {
+            let p = prime_factor(m.clone());
+            while (m.clone() % p.clone()).is_zero() {
+                m = m / p.clone();
+            }
+            let primes = if p == small(2) {
+                [Self::new(T::one(), T::one()), Self::zero()]
+            } else if p.clone() % small::<T>(4) == small(3) {
+                [Self::from(p), Self::zero()]
+            } else {
+                let pi = prime_above(p);
+                let conj = normalize(pi.conj()).0;
+                [pi, conj]
+            };
+            for pi in primes.iter().filter(|pi| !pi.is_zero()) {
+                let mut k = 0;
+                while let Some(q) = exact_div(&z, pi) {
+                    z = q;
+                    k += 1;
+                }
+                if k > 0 {
+                    f(pi.clone(), k);
+                }
+            }
+        }
+        z
+    }
+}
+
+/// Euclidean division of Gaussian integers, with the quotient of
+/// [`div_round`](Complex::div_round), so the remainder has at most half the
+/// norm of the divisor.  Unlike for real integers, there is no sign to make
+/// the remainder unique, and it may be any of up to four that small.
+impl<T: Clone + Num + Signed + Ord> Euclid for Complex<T> {
+    #[inline]
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_round(v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.clone() - v.clone() * self.div_round(v)
+    }
+
+    #[inline]
+    fn div_rem_euclid(&self, v: &Self) -> (Self, Self) {
+        let q = self.div_round(v);
+        (q.clone(), self.clone() - v.clone() * q)
+    }
+}
+
+/// Euclidean division as for [`Euclid`], returning `None` when the divisor is
+/// zero or when any intermediate product overflows.
+impl<T> CheckedEuclid for Complex<T>
+where
+    T: Clone + Num + Signed + Ord + CheckedAdd + CheckedSub + CheckedMul,
+{
+    #[inline]
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+        let n = checked_dot(&v.re, &v.re, &v.im, &v.im)?;
+        if n.is_zero() {
+            return None;
+        }
+        let re = checked_dot(&self.re, &v.re, &self.im, &v.im)?;
+        let im = checked_cross(&self.im, &v.re, &self.re, &v.im)?;
+        Some(Complex::new(round_div(re, &n), round_div(im, &n)))
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+        self.checked_div_rem_euclid(v).map(|(_, r)| r)
+    }
+
+    fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        let q = self.checked_div_euclid(v)?;
+        let re = checked_cross(&v.re, &q.re, &v.im, &q.im)?;
+        let im = checked_dot(&v.re, &q.im, &v.im, &q.re)?;
+        let r = Complex::new(self.re.checked_sub(&re)?, self.im.checked_sub(&im)?);
+        Some((q, r))
+    }
+}
+
+/// Returns `a b + c d`, or `None` on overflow.
+pub(crate) fn checked_dot<T: CheckedAdd + CheckedMul>(a: &T, b: &T, c: &T, d: &T) -> Option<T> {
+    a.checked_mul(b)?.checked_add(&c.checked_mul(d)?)
+}
+
+/// Returns `a b - c d`, or `None` on overflow.
+pub(crate) fn checked_cross<T: CheckedSub + CheckedMul>(a: &T, b: &T, c: &T, d: &T) -> Option<T> {
+    a.checked_mul(b)?.checked_sub(&c.checked_mul(d)?)
+}
+
+/// Returns `n` as a `T`.
+fn small<T: Clone + Num>(n: u32) -> T {
+    let two = T::one() + T::one();
+    (0..32).rev().fold(T::zero(), |acc, bit| {
+        let acc = acc * two.clone();
+        if n >> bit & 1 == 1 {
+            acc + T::one()
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns `a / n` rounded to the nearest integer, for `n > 0`, with ties
+/// toward zero.
+fn round_div<T: Clone + Num + Signed + Ord>(a: T, n: &T) -> T {
+    let q = a.clone() / n.clone();
+    let r = a % n.clone();
+    // 2r > n or 2r < -n, without overflow
+    if r.is_positive() && r > n.clone() - r.clone() {
+        q + T::one()
+    } else if r.is_negative() && -r.clone() > n.clone() + r {
+        q - T::one()
+    } else {
+        q
+    }
+}
+
+/// Returns the floor of `a / n`, for `n > 0`.
+pub(crate) fn floor_div<T: Clone + Num + Signed>(a: T, n: &T) -> T {
+    let q = a.clone() / n.clone();
+    if (a % n.clone()).is_negative() {
+        q - T::one()
+    } else {
+        q
+    }
+}
+
+/// Returns the associate of `z` with a positive real part and a non-negative
+/// imaginary part, and the unit that it was multiplied by.
+fn normalize<T: Clone + Num + Signed + Ord>(z: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let (zero, one) = (T::zero(), T::one());
+    let unit = if z.re.is_positive() && !z.im.is_negative() || z.is_zero() {
+        Complex::new(one, zero)
+    } else if !z.re.is_positive() && z.im.is_positive() {
+        Complex::new(zero, -one)
+    } else if z.re.is_negative() && !z.im.is_positive() {
+        Complex::new(-one, zero)
+    } else {
+        Complex::new(zero, one)
+    };
+    (z * unit.clone(), unit)
+}
+
+/// Returns `a / b` if `b` divides `a`.
+fn exact_div<T: Clone + Num + Signed>(a: &Complex<T>, b: &Complex<T>) -> Option<Complex<T>> {
+    let n = b.norm_sqr();
+    let z = a * b.conj();
+    if (z.re.clone() % n.clone()).is_zero() && (z.im.clone() % n.clone()).is_zero() {
+        Some(Complex::new(z.re / n.clone(), z.im / n))
+    } else {
+        None
+    }
+}
+
+/// Returns a normalized Gaussian prime `π` with norm `p`, for a prime
+/// `p ≡ 1 (mod 4)`.
+///
+/// With `x² ≡ -1 (mod p)`, the Euclidean algorithm on `p` and `x` reaches
+/// the first two remainders below `√p`, which are the components of `π`, by
+/// the Hermite–Serret method.  Every product here is reduced modulo `p`, so
+/// nothing overflows.
+fn prime_above<T: Clone + Num + Signed + Ord>(p: T) -> Complex<T> {
+    let p1 = p.clone() - T::one();
+    let half = p1.clone() / small(2);
+    let quarter = half.clone() / small(2);
+    // a quadratic non-residue c has c^((p - 1)/4) squaring to -1
+    let mut c = small::<T>(2);
+    while pow_mod(c.clone(), half.clone(), &p) != p1 {
+        c = c + T::one();
+    }
+    let x = pow_mod(c, quarter, &p);
+    let (mut a, mut b) = (p, x);
+    // until b² < p, which is b ≤ (p - 1)/b as p is not a square
+    while b > p1.clone() / b.clone() {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    let r = a % b.clone();
+    Complex::new(b, r)
+}
+
+/// Returns a prime factor of `n > 1`.
+fn prime_factor<T: Clone + Num + Signed + Ord>(mut n: T) -> T {
+    for &p in &SMALL_PRIMES {
+        let p = small::<T>(p);
+        if (n.clone() % p.clone()).is_zero() {
+            return p;
+        }
+    }
+    while !is_prime(&n) {
+        let d = pollard_rho(&n);
+        let e = n / d.clone();
+        n = d.min(e);
+    }
+    n
+}
+
+/// Returns `true` if the rational integer `n` is prime.
+fn is_prime<T: Clone + Num + Signed + Ord>(n: &T) -> bool {
+    if *n < small(2) {
+        return false;
+    }
+    for &p in &SMALL_PRIMES {
+        let p = small::<T>(p);
+        if *n == p {
+            return true;
+        }
+        if (n.clone() % p).is_zero() {
+            return false;
+        }
+    }
+    // n < 41², without forming 41² in a small type
+    if n.clone() / small(41) < small(41) {
+        return true;
+    }
+
+    // Miller–Rabin, with n - 1 = d 2ˢ
+    let n1 = n.clone() - T::one();
+    let mut d = n1.clone();
+    let mut s = 0;
+    while (d.clone() % small::<T>(2)).is_zero() {
+        d = d / small(2);
+        s += 1;
+    }
+    SMALL_PRIMES.iter().all(|&a| {
+        let mut x = pow_mod(small(a), d.clone(), n);
+        if x.is_one() || x == n1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x.clone(), x, n);
+            if x == n1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns a non-trivial factor of the odd composite `n`, by Pollard's rho
+/// method with `x ↦ x² + c`.
+fn pollard_rho<T: Clone + Num + Signed + Ord>(n: &T) -> T {
+    let mut c = T::one();
+    loop {
+        let f = |x: T| add_mod(mul_mod(x.clone(), x, n), c.clone(), n);
+        let (mut x, mut y) = (small::<T>(2), small::<T>(2));
+        let mut d = T::one();
+        while d.is_one() {
+            x = f(x);
+            y = f(f(y));
+            d = gcd((x.clone() - y.clone()).abs(), n.clone());
+        }
+        if d != *n {
+            return d;
+        }
+        c = c + T::one();
+    }
+}
+
+fn gcd<T: Clone + Num>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Returns `a + b (mod n)` for `0 ≤ a, b < n`, without overflow.
+fn add_mod<T: Clone + Num + Ord>(a: T, b: T, n: &T) -> T {
+    let gap = n.clone() - b.clone();
+    if a >= gap {
+        a - gap
+    } else {
+        a + b
+    }
+}
+
+/// Returns `a b (mod n)` for `0 ≤ a, b < n`, without overflow, by doubling.
+fn mul_mod<T: Clone + Num + Ord>(mut a: T, mut b: T, n: &T) -> T {
+    let two = T::one() + T::one();
+    let mut acc = T::zero();
+    while !b.is_zero() {
+        if (b.clone() % two.clone()).is_one() {
+            acc = add_mod(acc, a.clone(), n);
+        }
+        a = add_mod(a.clone(), a, n);
+        b = b / two.clone();
+    }
+    acc
+}
+
+/// Returns `aᵉ (mod n)` for `0 ≤ a < n`.
+fn pow_mod<T: Clone + Num + Ord>(mut a: T, mut e: T, n: &T) -> T {
+    let two = T::one() + T::one();
+    let mut acc = T::one();
+    while !e.is_zero() {
+        if (e.clone() % two.clone()).is_one() {
+            acc = mul_mod(acc, a.clone(), n);
+        }
+        a = mul_mod(a.clone(), a, n);
+        e = e / two.clone();
+    }
+    acc
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_prime, normalize};
+    use crate::Complex;
+    use num_traits::{CheckedEuclid, Euclid, One, Zero};
+
+    fn is_prime_naive(n: i64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    }
+
+    #[test]
+    fn test_is_prime() {
+        for n in -10..20_000 {
+            assert_eq!(is_prime(&n), is_prime_naive(n), "{}", n);
+        }
+        // strong pseudoprimes to several small bases
+        for &n in &[3_215_031_751i64, 2_152_302_898_747, 3_474_749_660_383] {
+            assert!(!is_prime(&n));
+        }
+        assert!(is_prime(&2_305_843_009_213_693_951i64));
+        assert!(is_prime(
+            &170_141_183_460_469_231_731_687_303_715_884_105_727i128
+        ));
+        for n in i8::MIN..=i8::MAX {
+            assert_eq!(is_prime(&n), is_prime_naive(n.into()), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_division() {
+        for a in -9i64..=9 {
+            for b in -9i64..=9 {
+                let x = Complex::new(a, b);
+                for c in -4i64..=4 {
+                    for d in -4i64..=4 {
+                        let y = Complex::new(c, d);
+                        if y.is_zero() {
+                            assert_eq!(x.checked_div_euclid(&y), None);
+                            assert_eq!(x.checked_rem_euclid(&y), None);
+                            continue;
+                        }
+                        // the exact quotient is z / n
+                        let (z, n) = (x * y.conj(), y.norm_sqr());
+
+                        assert_eq!(x.div_rem(&y), (x / y, x % y));
+
+                        let q = x.div_round(&y);
+                        let r = x - y * q;
+                        assert!(2 * r.norm_sqr() <= n);
+                        assert!((2 * (z.re - q.re * n)).abs() <= n);
+                        assert!((2 * (z.im - q.im * n)).abs() <= n);
+                        assert_eq!(x.div_rem_euclid(&y), (q, r));
+                        assert_eq!((x.div_euclid(&y), x.rem_euclid(&y)), (q, r));
+                        assert_eq!(x.checked_div_rem_euclid(&y), Some((q, r)));
+
+                        let q = x.div_floor(&y);
+                        assert!(q.re * n <= z.re && z.re < (q.re + 1) * n);
+                        assert!(q.im * n <= z.im && z.im < (q.im + 1) * n);
+                        assert!((x - y * q).norm_sqr() < 2 * n);
+                    }
+                }
+            }
+        }
+
+        // ties round towards zero
+        let two = Complex::new(2, 0);
+        assert_eq!(Complex::new(3, -3).div_round(&two), Complex::new(1, -1));
+        assert_eq!(Complex::new(-5, 5).div_round(&two), Complex::new(-2, 2));
+
+        let max = Complex::new(i32::MAX, i32::MAX);
+        assert_eq!(max.checked_div_euclid(&Complex::new(2, 1)), None);
+        assert_eq!(
+            max.checked_div_rem_euclid(&Complex::new(1, 0)),
+            Some((max, Complex::new(0, 0)))
+        );
+    }
+
+    #[test]
+    fn test_gcd() {
+        let range = -12..=12;
+        for a in range.clone() {
+            for b in range.clone() {
+                let x = Complex::new(a, b);
+                for c in (-6..=6).step_by(3) {
+                    for d in (-5..=5).step_by(2) {
+                        let y = Complex::new(c, d);
+                        let g = x.gcd(&y);
+                        assert_eq!(g, y.gcd(&x));
+                        let (h, s, t) = x.extended_gcd(&y);
+                        assert_eq!(h, g);
+                        assert_eq!(s * x + t * y, g);
+                        if g.is_zero() {
+                            assert!(x.is_zero() && y.is_zero());
+                            continue;
+                        }
+                        assert!(g.re > 0 && g.im >= 0);
+                        assert!((x % g).is_zero() && (y % g).is_zero());
+                        // the cofactors are coprime
+                        let one = Complex::one();
+                        assert_eq!((x / g).gcd(&(y / g)), one, "{} {}", x, y);
+                    }
+                }
+            }
+        }
+        let a = Complex::new(1_000_003i64, 12_345);
+        assert_eq!(a.gcd(&(a * Complex::new(7, -3))), normalize(a).0);
+    }
+
+    #[test]
+    fn test_is_gaussian_prime() {
+        let divides = |w: Complex<i64>, z: Complex<i64>| {
+            let (q, m) = (z * w.conj(), w.norm_sqr());
+            q.re % m == 0 && q.im % m == 0
+        };
+        for a in -20i64..=20 {
+            for b in -20i64..=20 {
+                let z = Complex::new(a, b);
+                let n = z.norm_sqr();
+                // a non-unit with no divisors but units and associates
+                let naive = n > 1
+                    && (-20i64..=20).all(|c| {
+                        (-20i64..=20).all(|d| {
+                            let w = Complex::new(c, d);
+                            let m = w.norm_sqr();
+                            m <= 1 || m == n || !divides(w, z)
+                        })
+                    });
+                assert_eq!(z.is_gaussian_prime(), naive, "{}", z);
+            }
+        }
+        assert!(Complex::new(1_000_003i64, 0).is_gaussian_prime());
+        assert!(!Complex::new(1_000_033i64, 0).is_gaussian_prime());
+        for n in i8::MIN + 1..=i8::MAX {
+            let expected = Complex::new(0, i64::from(n)).is_gaussian_prime();
+            assert_eq!(Complex::new(0, n).is_gaussian_prime(), expected, "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_sum_of_two_squares() {
+        for n in -3i64..2_000 {
+            let naive = (0..=n)
+                .take_while(|a| a * a <= n)
+                .any(|a| (0..=a).any(|b| a * a + b * b == n));
+            match Complex::sum_of_two_squares(n) {
+                Some(z) => {
+                    assert!(naive);
+                    assert_eq!(z.norm_sqr(), n);
+                    assert!(z.re >= z.im && z.im >= 0);
+                }
+                None => assert!(!naive, "{}", n),
+            }
+        }
+        let n = 1_000_000_009i64 * 998_244_353;
+        let z = Complex::sum_of_two_squares(n).unwrap();
+        assert_eq!(z.norm_sqr(), n);
+
+        // near the limits of the type, where p² overflows
+        for n in 0..=i8::MAX {
+            let expected = Complex::sum_of_two_squares(i64::from(n));
+            let z = Complex::sum_of_two_squares(n).map(|z| Complex::new(z.re.into(), z.im.into()));
+            assert_eq!(z, expected, "{}", n);
+        }
+        for &p in &[1_000_000_000_061i64, 9_223_372_036_854_775_549] {
+            let z = Complex::sum_of_two_squares(p).unwrap();
+            let (a, b) = (i128::from(z.re), i128::from(z.im));
+            assert_eq!(a * a + b * b, i128::from(p));
+        }
+    }
+
+    #[test]
+    fn test_factorize_with() {
+        for a in -25i64..=25 {
+            for b in -25i64..=25 {
+                let z = Complex::new(a, b);
+                let mut factors = std::vec::Vec::new();
+                let unit = z.factorize_with(|p, e| factors.push((p, e)));
+                if z.is_zero() {
+                    assert!(unit.is_zero() && factors.is_empty());
+                    continue;
+                }
+                assert_eq!(unit.norm_sqr(), 1);
+                let product = factors.iter().fold(unit, |acc, &(p, e)| acc * p.powu(e));
+                assert_eq!(product, z);
+                for (i, &(p, e)) in factors.iter().enumerate() {
+                    assert!(p.is_gaussian_prime() && e > 0);
+                    assert_eq!(normalize(p).0, p);
+                    assert!(factors[..i].iter().all(|&(q, _)| q != p));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_factorize() {
+        for a in -25i64..=25 {
+            for b in -25i64..=25 {
+                let z = Complex::new(a, b);
+                let (unit, factors) = z.factorize();
+                if z.is_zero() {
+                    assert!(unit.is_zero() && factors.is_empty());
+                    continue;
+                }
+                assert_eq!(unit.norm_sqr(), 1);
+                let product = factors.iter().fold(unit, |acc, &(p, e)| acc * p.powu(e));
+                assert_eq!(product, z);
+                for w in factors.windows(2) {
+                    assert!((w[0].0.norm_sqr(), w[0].0.re) < (w[1].0.norm_sqr(), w[1].0.re));
+                }
+                for &(p, e) in &factors {
+                    assert!(p.is_gaussian_prime() && e > 0);
+                    assert_eq!(normalize(p).0, p);
+                }
+            }
+        }
+
+        // a norm with large prime factors, which needs Pollard's rho
+        let p = Complex::sum_of_two_squares(1_000_037i64).unwrap();
+        let q = Complex::sum_of_two_squares(1_000_033i64).unwrap();
+        let z = p * q.conj() * Complex::new(0, -7);
+        let (unit, factors) = z.factorize();
+        assert_eq!(unit, Complex::new(-1, 0));
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors[0], (Complex::new(7, 0), 1));
+
+        // 2 p, with a prime p whose square overflows
+        let z = Complex::new(1_000_003i64, 999_983);
+        let (unit, factors) = z.factorize();
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors[0], (Complex::new(1, 1), 1));
+        assert_eq!(factors[1].0.norm_sqr(), 999_986_000_149);
+        assert_eq!(unit * factors[0].0 * factors[1].0, z);
+    }
+}
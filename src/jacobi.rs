@@ -0,0 +1,417 @@
+//! Jacobi elliptic functions of complex argument and parameter.
+//!
+//! The argument is first reduced by the periods `2K` and `2iK'`, and by the
+//! quarter period `iK'` if that brings it closer to the real axis, and then
+//! `sn`, `cn` and `dn` come from descending Landen (Gauss) transformations,
+//! DLMF 22.7.1–3, down to a parameter small enough that they are `sin`, `cos`
+//! and `1`.  Parameters outside the unit circle are first brought inside by
+//! the reciprocal-modulus transformation, DLMF 22.17.2–4.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::Complex;
+
+/// The parameter is squared at each Landen step, once it is small, so this is
+/// far more steps than are ever needed.
+const LANDEN_STEPS: usize = 64;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the Jacobi elliptic functions `(sn(u|m), cn(u|m), dn(u|m))`,
+    /// with `u = self`.
+    ///
+    /// These are doubly periodic in `u`: with `K = K(m)` and `K' = K(1 - m)`
+    /// the complete elliptic integrals of [`elliptic_k`](Complex::elliptic_k),
+    /// `sn` has periods `4K` and `2iK'`, `cn` has `4K` and `2K + 2iK'`, and
+    /// `dn` has `2K` and `4iK'`, with simple poles at `iK'` and its
+    /// translates.  The parameter `m` may be any complex number: although
+    /// `K` and `K'` have cuts, the functions themselves have none in `m`, and
+    /// for `m` outside the unit circle they come from
+    /// `sn(u|m) = sn(√m u|1/m)/√m`, `cn(u|m) = dn(√m u|1/m)` and
+    /// `dn(u|m) = cn(√m u|1/m)`, which don't depend on the sign of `√m`.
+    /// Real `u` and real `m` give real results.
+    ///
+    /// The `f64` results are within a few times `1e-15` relative error for
+    /// `u` within a period or so of the origin.  The reduction by the
+    /// periods loses accuracy in proportion to `|u|/|K|` and `|u|/|K'|`, as
+    /// `sin` does for large arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let m = Complex64::new(0.5, 0.0);
+    /// let (sn, cn, dn) = Complex64::new(0.7, 0.3).jacobi_elliptic(m);
+    /// assert!((sn - Complex64::new(0.6562399832872098, 0.2112452708384802)).norm() < 1e-15);
+    ///
+    /// // sn² + cn² = 1 and dn² + m sn² = 1
+    /// assert!((sn * sn + cn * cn - 1.0).norm() < 1e-15);
+    /// assert!((dn * dn + m * sn * sn - 1.0).norm() < 1e-15);
+    ///
+    /// // m = 0 and m = 1 are the circular and hyperbolic functions
+    /// let u = Complex64::new(0.2, -1.5);
+    /// assert_eq!(u.jacobi_elliptic(Complex64::new(0.0, 0.0)).0, u.sin());
+    /// assert_eq!(u.jacobi_elliptic(Complex64::new(1.0, 0.0)).0, u.tanh());
+    /// ```
+    pub fn jacobi_elliptic(self, m: Self) -> (Self, Self, Self) {
+        let one = Self::one();
+        if m.is_zero() {
+            return (self.sin(), self.cos(), one);
+        }
+        if m.is_one() {
+            let sech = self.cosh().finv();
+            return (self.tanh(), sech, sech);
+        }
+        if m.norm() > T::one() {
+            let k = m.sqrt();
+            let (sn, cn, dn) = (self * k).jacobi_elliptic(m.finv());
+            return (sn.fdiv(k), dn, cn);
+        }
+
+        // The periods 2K and 2iK'.  K' = π / (2 agm(1, √m)) is accurate for
+        // small m, where 1 - m rounds to 1, and has the same continuation in
+        // √m as the quarter-period identities below.
+        let two = T::one() + T::one();
+        let k = m.sqrt();
+        let k_prime = Self::from(T::FRAC_PI_2()).fdiv(one.agm(k));
+        let v1 = m.elliptic_k().scale(two);
+        let v2 = Self::new(-k_prime.im, k_prime.re).scale(two);
+
+        // the coordinates of u in the basis (v1, v2), by Cramer's rule
+        let cross = |a: Self, b: Self| a.re * b.im - a.im * b.re;
+        let det = cross(v1, v2);
+        let odd = |n: T| !(n / two).fract().is_zero();
+        let mut u = self;
+        let (mut neg_sc, mut neg_cd) = (false, false);
+        let p = (cross(u, v2) / det).round();
+        if p.is_finite() && !p.is_zero() {
+            u = u - v1.scale(p);
+            neg_sc = odd(p);
+        }
+        let q = (cross(v1, u) / det).round();
+        if q.is_finite() && !q.is_zero() {
+            u = u - v2.scale(q);
+            neg_cd = odd(q);
+        }
+
+        // sn(u ± iK') = 1/(k sn u), cn(u ± iK') = ∓i dn u/(k sn u), and
+        // dn(u ± iK') = ∓i cn u/sn u
+        let beta = cross(v1, u) / det;
+        let quarter = two.powi(-2);
+        let (sn, cn, dn) = if beta.abs() > quarter {
+            let shift = if beta > T::zero() {
+                T::one()
+            } else {
+                -T::one()
+            };
+            let (sn, cn, dn) = landen(u - v2.scale(shift / two), m);
+            let ksn = k * sn;
+            let i = Self::new(T::zero(), -shift);
+            (ksn.finv(), (i * dn).fdiv(ksn), (i * cn).fdiv(sn))
+        } else {
+            landen(u, m)
+        };
+        let sn = if neg_sc { -sn } else { sn };
+        let cn = if neg_sc != neg_cd { -cn } else { cn };
+        let dn = if neg_cd { -dn } else { dn };
+        (sn, cn, dn)
+    }
+
+    /// Computes the Jacobi elliptic function `sn(u|m)`, with `u = self`.
+    ///
+    /// See [`jacobi_elliptic`](Complex::jacobi_elliptic) for the periods,
+    /// branches and accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// let sn = Complex64::new(0.5, 0.0).jacobi_sn(Complex64::new(0.3, 0.0));
+    /// assert!((sn.re - 0.4742156227118206).abs() < 1e-15);
+    /// assert_eq!(sn.im, 0.0);
+    /// ```
+    pub fn jacobi_sn(self, m: Self) -> Self {
+        self.jacobi_elliptic(m).0
+    }
+
+    /// Computes the Jacobi elliptic function `cn(u|m)`, with `u = self`.
+    ///
+    /// See [`jacobi_elliptic`](Complex::jacobi_elliptic) for the periods,
+    /// branches and accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // cn(K|m) = 0
+    /// let m = Complex64::new(0.8, 0.0);
+    /// assert!(m.elliptic_k().jacobi_cn(m).norm() < 1e-15);
+    /// ```
+    pub fn jacobi_cn(self, m: Self) -> Self {
+        self.jacobi_elliptic(m).1
+    }
+
+    /// Computes the Jacobi elliptic function `dn(u|m)`, with `u = self`.
+    ///
+    /// See [`jacobi_elliptic`](Complex::jacobi_elliptic) for the periods,
+    /// branches and accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // dn(K|m) = √(1 - m)
+    /// let m = Complex64::new(0.8, 0.0);
+    /// let dn = m.elliptic_k().jacobi_dn(m);
+    /// assert!((dn.re - 0.2f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn jacobi_dn(self, m: Self) -> Self {
+        self.jacobi_elliptic(m).2
+    }
+}
+
+/// Descends by Landen transformations `m → k₁²`, `u → u/(1 + k₁)` with
+/// `k₁ = (1 - √(1 - m))/(1 + √(1 - m))`, until `m sin² u` is negligible, and
+/// ascends back with DLMF 22.7.1–3.  The principal `√(1 - m)` keeps
+/// `|k₁| < 1` for `m` off `[1, ∞)`.
+fn landen<T: Float>(u: Complex<T>, m: Complex<T>) -> (Complex<T>, Complex<T>, Complex<T>) {
+    let one = Complex::<T>::one();
+    let two = T::one() + T::one();
+    let ln_eps = T::epsilon().ln();
+    let mut moduli = [Complex::zero(); LANDEN_STEPS];
+    let (mut u, mut m) = (u, m);
+    let mut n = 0;
+    while n < LANDEN_STEPS && m.norm().ln() + two * u.im.abs() >= ln_eps {
+        let t = one + (one - m).sqrt();
+        let k = m.fdiv(t * t);
+        u = u.fdiv(one + k);
+        m = k * k;
+        moduli[n] = k;
+        n += 1;
+    }
+    let (mut sn, mut cn, mut dn) = (u.sin(), u.cos(), one);
+    for &k in moduli[..n].iter().rev() {
+        let ksn2 = k * sn * sn;
+        let den = one + ksn2;
+        sn = (sn * (one + k)).fdiv(den);
+        cn = (cn * dn).fdiv(den);
+        dn = (one - ksn2).fdiv(den);
+    }
+    (sn, cn, dn)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::Complex64;
+    use num_traits::One;
+
+    // (u, m, [sn, cn, dn]) with references from mpmath
+    type Case = ((f64, f64), (f64, f64), [(f64, f64); 3]);
+
+    fn c((re, im): (f64, f64)) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    fn close_rel(a: Complex64, b: Complex64, tol: f64) -> bool {
+        close_to_tol(a, b, tol * b.norm().max(1.0))
+    }
+
+    const PARAMETERS: [(f64, f64); 6] = [
+        (0.8, 0.0),
+        (-3.0, 0.0),
+        (0.3, 0.2),
+        (-2.0, 0.5),
+        (0.999, -0.01),
+        (9.5367431640625e-7, 0.0),
+    ];
+
+    const ARGUMENTS: [(f64, f64); 4] = [(0.4, 0.0), (1.1, -0.7), (-2.0, 0.3), (0.2, 1.9)];
+
+    #[test]
+    fn test_jacobi_elliptic() {
+        let cases: [Case; 9] = [
+            (
+                (0.8, 0.0),
+                (0.5, 0.0),
+                [
+                    (0.6909348508664388, 0.0),
+                    (0.7229170297192977, 0.0),
+                    (0.8725276591198047, 0.0),
+                ],
+            ),
+            (
+                (2.5, 0.0),
+                (-3.0, 0.0),
+                [
+                    (-0.3556326110129365, 0.0),
+                    (-0.9346258320761959, 0.0),
+                    (1.1744886811066488, 0.0),
+                ],
+            ),
+            (
+                (0.3, 1.2),
+                (0.9, 0.0),
+                [
+                    (1.3185258445417491, 1.534683710877825),
+                    (1.7284053642767414, -1.170743957298698),
+                    (1.660644475338833, -1.0966634637549975),
+                ],
+            ),
+            (
+                (-1.0, 0.5),
+                (0.4, 0.5),
+                [
+                    (-0.8538845380172257, 0.30376617075662565),
+                    (0.7058319998951332, 0.3674829653803168),
+                    (0.7015201373839808, -0.07905508613948464),
+                ],
+            ),
+            (
+                (2.0, -3.0),
+                (-2.0, 1.0),
+                [
+                    (1.4529505791492965, -0.8910928614838683),
+                    (-1.070453811332183, -1.2095000040753883),
+                    (1.9524268118282921, -1.6635385293277807),
+                ],
+            ),
+            (
+                (0.5, 0.5),
+                (5.0, 0.0),
+                [
+                    (0.6289192082681532, 0.21240149970517694),
+                    (0.8221759476522789, -0.16247542075512575),
+                    (0.624857191091744, -1.0689113043266154),
+                ],
+            ),
+            (
+                (1.5, 0.0),
+                (0.999999, 0.0),
+                [
+                    (0.9051484121668781, 0.0),
+                    (0.42509569740445413, 0.0),
+                    (0.4250966610612547, 0.0),
+                ],
+            ),
+            (
+                (4.0, 7.0),
+                (1e-8, 0.0),
+                [
+                    (-415.1885447264774, -358.05543067026053),
+                    (-358.0560262681031, 415.18785409321515),
+                    (0.9997801921443987, -0.0014869319712419997),
+                ],
+            ),
+            (
+                (-0.2, 2.0),
+                (20.0, -10.0),
+                [
+                    (1.0183810334196857, -0.7574429981584102),
+                    (1.0416370139982216, 0.7405320402932455),
+                    (4.656348321663024, 3.8107569725223405),
+                ],
+            ),
+        ];
+        for &(u, m, [sn, cn, dn]) in &cases {
+            let (u, m) = (c(u), c(m));
+            let (s, cc, d) = u.jacobi_elliptic(m);
+            assert!(close_rel(s, c(sn), 1e-14));
+            assert!(close_rel(cc, c(cn), 1e-14));
+            assert!(close_rel(d, c(dn), 1e-14));
+            // conjugate symmetry
+            let (s, cc, d) = u.conj().jacobi_elliptic(m.conj());
+            assert!(close_rel(s, c(sn).conj(), 1e-14));
+            assert!(close_rel(cc, c(cn).conj(), 1e-14));
+            assert!(close_rel(d, c(dn).conj(), 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_jacobi_periods() {
+        for &m in &PARAMETERS {
+            let m = c(m);
+            let k = m.elliptic_k();
+            let ik_prime = (Complex64::one() - m).elliptic_k() * Complex64::i();
+            for &u in &ARGUMENTS {
+                let u = c(u);
+                let (sn, cn, dn) = u.jacobi_elliptic(m);
+                assert!(close_rel((u + 4.0 * k).jacobi_sn(m), sn, 1e-13));
+                assert!(close_rel((u + 2.0 * ik_prime).jacobi_sn(m), sn, 1e-13));
+                assert!(close_rel((u + 2.0 * k).jacobi_sn(m), -sn, 1e-13));
+                assert!(close_rel(
+                    (u + 2.0 * (k + ik_prime)).jacobi_cn(m),
+                    cn,
+                    1e-13
+                ));
+                assert!(close_rel((u + 2.0 * k).jacobi_cn(m), -cn, 1e-13));
+                assert!(close_rel((u + 2.0 * k).jacobi_dn(m), dn, 1e-13));
+                assert!(close_rel((u + 2.0 * ik_prime).jacobi_dn(m), -dn, 1e-13));
+                // the quarter periods, where for negative m, K' is on its cut
+                // and which side it takes isn't tied to the sign of √m
+                assert!(close_rel((u + k).jacobi_sn(m), cn / dn, 1e-13));
+                if m.re > 0.0 || m.im != 0.0 {
+                    assert!(close_rel(
+                        (u + ik_prime).jacobi_sn(m),
+                        (m.sqrt() * sn).inv(),
+                        1e-13
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_addition() {
+        for &m in &PARAMETERS {
+            let m = c(m);
+            for &u in &ARGUMENTS {
+                for &v in &ARGUMENTS {
+                    let (u, v) = (c(u), c(v));
+                    let (su, cu, du) = u.jacobi_elliptic(m);
+                    let (sv, cv, dv) = v.jacobi_elliptic(m);
+                    let den = 1.0 - m * su * su * sv * sv;
+                    let (s, cc, d) = (u + v).jacobi_elliptic(m);
+                    assert!(close_rel(s, (su * cv * dv + sv * cu * du) / den, 1e-13));
+                    assert!(close_rel(cc, (cu * cv - su * sv * du * dv) / den, 1e-13));
+                    assert!(close_rel(d, (du * dv - m * su * sv * cu * cv) / den, 1e-13));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_special() {
+        for &m in &PARAMETERS {
+            let m = c(m);
+            for &u in &ARGUMENTS {
+                let u = c(u);
+                let (sn, cn, dn) = u.jacobi_elliptic(m);
+                let size = sn.norm_sqr() + cn.norm_sqr() + dn.norm_sqr();
+                let one = Complex64::one();
+                assert!(close_to_tol(sn * sn + cn * cn, one, 1e-14 * size));
+                assert!(close_to_tol(dn * dn + m * sn * sn, one, 1e-14 * size));
+                // sn is odd, cn and dn are even
+                let (s, cc, d) = (-u).jacobi_elliptic(m);
+                assert!(close_rel(s, -sn, 1e-15));
+                assert!(close_rel(cc, cn, 1e-15));
+                assert!(close_rel(d, dn, 1e-15));
+                // the reciprocal modulus
+                let k = m.sqrt();
+                assert!(close_rel((u * k).jacobi_sn(m.inv()), k * sn, 1e-13));
+            }
+        }
+        let u = c((0.7, -0.4));
+        let zero = Complex64::new(0.0, 0.0);
+        assert_eq!(
+            u.jacobi_elliptic(zero),
+            (u.sin(), u.cos(), Complex64::one())
+        );
+        let m = c((0.6, 0.0));
+        let (sn, cn, dn) = zero.jacobi_elliptic(m);
+        assert_eq!((sn, cn, dn), (zero, Complex64::one(), Complex64::one()));
+    }
+}
@@ -0,0 +1,270 @@
+//! The Lambert W function, following R. M. Corless, G. H. Gonnet,
+//! D. E. G. Hare, D. J. Jeffrey and D. E. Knuth, "On the Lambert W
+//! function", Adv. Comput. Math. 5(1), 1996.
+
+use core::f64;
+
+use num_traits::{Float, FloatConst, Zero};
+
+use crate::{constant, horner, Complex};
+
+/// The rounding error of `f64::consts::E`, so that `e z + 1` can be computed
+/// accurately near the branch point.
+const E_LO: f64 = 1.4456468917292502e-16;
+
+/// The series of `W` around the branch point in `p = √(2(e z + 1))`, from
+/// `p^23` down to `p^0`.
+const BRANCH_SERIES: [f64; 24] = [
+    1.7790345805079586e-05,
+    -2.63380647472311e-05,
+    3.907668486743905e-05,
+    -5.811360750441382e-05,
+    8.665035805208128e-05,
+    -0.00012957426685274883,
+    0.00019438727605453933,
+    -0.00029267722472962746,
+    0.0004424730618146209,
+    -0.0006720616311561362,
+    0.0010262633205076071,
+    -0.0015769303446867841,
+    0.0024408779911439826,
+    -0.0038112980348919993,
+    0.006014543252956118,
+    -0.009616892024299432,
+    0.01563563253233392,
+    -0.02598471487360376,
+    0.044502314814814814,
+    -0.07962962962962963,
+    0.1527777777777778,
+    -0.3333333333333333,
+    1.0,
+    -1.0,
+];
+
+/// Below this `|p|`, the branch point series is accurate on its own.  Up to
+/// `|e z + 1|` of `BRANCH_GUESS`, it is only used as the first guess.
+const BRANCH_EXACT: f64 = 0.25;
+const BRANCH_GUESS: f64 = 0.8;
+
+/// The Padé approximant `W(z) ≈ z (1 + a₁z + a₂z²) / (1 + b₁z + b₂z²)`
+/// around the origin, as `[a₂, a₁, 1]` and `[b₂, b₁, 1]`.
+const PADE_NUM: [f64; 3] = [17.0 / 60.0, 1.9, 1.0];
+const PADE_DEN: [f64; 3] = [101.0 / 60.0, 2.9, 1.0];
+
+/// Halley's iteration converges cubically, so this is far more than it ever
+/// needs.
+const HALLEY_STEPS: u32 = 32;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes branch `k` of the Lambert W function, the solutions `w` of
+    /// `w e^w = self`.
+    ///
+    /// The branches follow Corless et al.: branch 0 is the principal branch,
+    /// analytic except for the cut along `(-∞, -1/e]`, and real for real
+    /// arguments from `-1/e` upwards.  The other branches have their cut along
+    /// `(-∞, 0]`, with branch `-1` also cut along `(-∞, -1/e]`, where it
+    /// meets the principal branch, and real on `[-1/e, 0)` approached from
+    /// above.  As for [`ln`](Complex::ln), the sign of a zero imaginary part
+    /// selects the side of a cut, and branch `k` of `conj(z)` is the conjugate
+    /// of branch `-k` of `z`.
+    ///
+    /// The result is refined from series and asymptotic approximations with
+    /// Halley's method, except near the branch point `-1/e`, where the series
+    /// in `√(2(ez + 1))` is used alone.  The `f64` result is within a few
+    /// times `1e-15` relative error.  `W` is zero at zero on the principal
+    /// branch and `-∞` on the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // the omega constant, Ω e^Ω = 1
+    /// let omega = Complex64::new(1.0, 0.0).lambert_w(0);
+    /// assert_eq!(omega, Complex64::new(0.5671432904097838, 0.0));
+    ///
+    /// // two real branches between -1/e and 0
+    /// let z = Complex64::new(-0.2, 0.0);
+    /// assert!((z.lambert_w(0).re + 0.2591711018190738).abs() < 1e-15);
+    /// assert!((z.lambert_w(-1).re + 2.5426413577735265).abs() < 1e-15);
+    ///
+    /// let w = Complex64::new(3.0, 4.0).lambert_w(2);
+    /// assert!((w * w.exp() - Complex64::new(3.0, 4.0)).norm() < 1e-14);
+    /// ```
+    pub fn lambert_w(self, k: i32) -> Self {
+        if self.is_zero() {
+            return if k == 0 {
+                self
+            } else {
+                Self::new(T::neg_infinity(), T::zero())
+            };
+        }
+        if !(self.re.is_finite() && self.im.is_finite()) {
+            return self.ln_branch(k);
+        }
+
+        let one = T::one();
+        let two = one + one;
+        // q = e z + 1, with e split in two to keep its rounding error out
+        let e = T::E();
+        let e_lo = constant::<T>(f64::consts::E - e.to_f64().unwrap() + E_LO);
+        let q = Self::new(
+            self.re.mul_add(e, one) + self.re * e_lo,
+            self.im * e + self.im * e_lo,
+        );
+        // the branches that meet at -1/e, approached from this side
+        let upper = !self.im.is_sign_negative();
+        let at_branch_point = k == 0 || (k == -1 && upper) || (k == 1 && !upper);
+        let real = self.im.is_zero()
+            && q.re >= T::zero()
+            && (k == 0 || (at_branch_point && self.re < T::zero()));
+
+        let mut w = if at_branch_point && q.norm() < constant(BRANCH_GUESS) {
+            let p = q.scale(two).sqrt();
+            let p = if k == 0 { p } else { -p };
+            let w = horner(&BRANCH_SERIES, p);
+            if p.norm() < constant(BRANCH_EXACT) {
+                return if real { Self::new(w.re, self.im) } else { w };
+            }
+            w
+        } else if k == 0
+            && self.re > -one
+            && self.re < constant(1.5)
+            && self.im.abs() < one
+            && self.re > self.im.abs() * constant(-2.5) - constant(0.2)
+        {
+            self * horner(&PADE_NUM, self) / horner(&PADE_DEN, self)
+        } else {
+            // W ~ L₁ - L₂ + L₂/L₁ + L₂(L₂ - 2)/2L₁², with L₁ = ln z + 2πik
+            // and L₂ = ln L₁
+            let l1 = self.ln_branch(k);
+            let l2 = l1.ln();
+            let r = l1.finv();
+            l1 - l2 + l2 * r + l2 * (l2 - two) * r * r / two
+        };
+
+        let tol = T::epsilon().sqrt();
+        for _ in 0..HALLEY_STEPS {
+            let ew = w.exp();
+            if ew.is_zero() || !(ew.re.is_finite() && ew.im.is_finite()) {
+                // the asymptotic guess is already as good as it gets
+                break;
+            }
+            let f = w * ew - self;
+            let w1 = w + one;
+            let delta = f.fdiv(ew * w1 - ((w + two) * f).fdiv(w1.scale(two)));
+            w = w - delta;
+            // the error is now roughly the cube of this step
+            if delta.norm() <= tol * w.norm() {
+                break;
+            }
+        }
+        if real {
+            Self::new(w.re, self.im)
+        } else {
+            w
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::test::{_0_0i, all_consts};
+    use crate::Complex64;
+    use std::f64;
+
+    // (k, z, W_k(z)) with references from mpmath
+    type Case = (i32, (f64, f64), (f64, f64));
+
+    #[test]
+    fn test_lambert_w() {
+        let cases: [Case; 20] = [
+            (0, (1.0, 0.0), (0.5671432904097838, 0.0)),
+            (0, (-0.2, 0.0), (-0.25917110181907377, 0.0)),
+            (0, (-0.36, 0.0), (-0.8060843159708176, 0.0)),
+            (0, (10.0, 0.0), (1.7455280027406994, 0.0)),
+            (0, (-1.0, 0.0), (-0.31813150520476413, 1.3372357014306895)),
+            (0, (3.0, 4.0), (1.281561806123776, 0.533095222020971)),
+            (0, (-0.5, 0.1), (-0.560112571030067, 0.6952089096099553)),
+            (
+                0,
+                (1e-5, 1e-5),
+                (9.999999997000108e-6, 9.999800003000001e-6),
+            ),
+            (0, (1e100, 0.0), (224.8431064451185, 0.0)),
+            (-1, (-0.2, 0.0), (-2.5426413577735265, 0.0)),
+            (-1, (-0.36, 0.0), (-1.2227701339785062, 0.0)),
+            (-1, (-1e-10, 0.0), (-26.295238819246926, 0.0)),
+            (-1, (3.0, 4.0), (0.2585674068669974, -3.8521166861614358)),
+            (-1, (1.0, 0.0), (-1.5339133197935746, -4.375185153061898)),
+            (1, (-0.2, 0.0), (-3.722320484923165, 7.387230210574593)),
+            (1, (3.0, 4.0), (-0.11691092896595325, 5.6188803987128235)),
+            (1, (-5.0, -2.0), (0.798751029218949, 2.2872777580658283)),
+            (2, (-3.0, 1.0), (-1.4724529615470296, 13.708414289734002)),
+            (-3, (1e-3, -7.0), (-0.9891612106700869, -18.796837750685)),
+            (5, (1e10, 1e10), (19.76343266714919, 31.19524518647292)),
+        ];
+        for &(k, (x, y), (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 4e-15 * expected.norm().max(1.0);
+            assert!(close_to_tol(
+                Complex64::new(x, y).lambert_w(k),
+                expected,
+                tol
+            ));
+        }
+
+        // W_k(z) e^{W_k(z)} = z on every branch
+        for &c in all_consts.iter().skip(1) {
+            for k in -3..=3 {
+                let w = c.lambert_w(k);
+                assert!(close_to_tol(w * w.exp(), c, 1e-14));
+            }
+        }
+
+        // branch 0 of the conjugate is the conjugate, also across the cut
+        let z = Complex64::new(-1.0, -0.0);
+        assert_eq!(z.lambert_w(0), z.conj().lambert_w(0).conj());
+        assert_eq!(z.lambert_w(2), z.conj().lambert_w(-2).conj());
+    }
+
+    #[test]
+    fn test_lambert_w_branch_point() {
+        // -1/e rounds to just past the branch point, where W is -1 + O(√δ)
+        let z = Complex64::new(-1.0 / f64::consts::E, 0.0);
+        let (w0, w1) = (z.lambert_w(0), z.lambert_w(-1));
+        assert!(close_to_tol(w0, Complex64::new(-1.0, 0.0), 1e-7));
+        assert_eq!(w1, w0.conj());
+        // branch 1 meets branch 0 from below the cut
+        assert_eq!(z.conj().lambert_w(1), w0);
+
+        // just inside the real interval, both real branches stay real
+        let z = Complex64::new(-0.36787944117, 0.0);
+        let (w0, w1) = (z.lambert_w(0), z.lambert_w(-1));
+        assert!(w0.im == 0.0 && w1.im == 0.0 && w0.re > -1.0 && w1.re < -1.0);
+        assert!(close_to_tol(w0 * w0.exp(), z, 1e-16));
+        assert!(close_to_tol(w1 * w1.exp(), z, 1e-16));
+        // the lower side of branch 1 is the conjugate of the upper side of -1
+        let z = Complex64::new(-0.36, -0.0);
+        assert_eq!(z.lambert_w(1), z.conj().lambert_w(-1).conj());
+        assert!(close_to_tol(
+            z.lambert_w(1),
+            Complex64::new(-1.2227701339785062, 0.0),
+            4e-15
+        ));
+    }
+
+    #[test]
+    fn test_lambert_w_special() {
+        assert_eq!(_0_0i.lambert_w(0), _0_0i);
+        let w = _0_0i.lambert_w(-1);
+        assert_eq!(w.re, f64::NEG_INFINITY);
+        let inf = Complex64::new(f64::INFINITY, 0.0);
+        assert_eq!(inf.lambert_w(0), inf);
+        assert!(Complex64::new(f64::NAN, 0.0).lambert_w(0).is_nan());
+        // no overflow in the iteration far from the origin
+        let w = Complex64::new(5e219, 0.0).lambert_w(0);
+        assert!((w.re / 499.6616421245343 - 1.0).abs() < 1e-15);
+    }
+}
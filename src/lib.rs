@@ -36,22 +36,54 @@ use num_traits::float::FloatCore;
 #[cfg(any(feature = "std", feature = "libm"))]
 use num_traits::float::{Float, FloatConst};
 
+#[cfg(any(feature = "std", feature = "libm"))]
+mod airy;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod bessel;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod branch;
 mod cast;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod compensated;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::compensated::{sum2, two_prod};
+mod eisenstein;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod elliptic;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod erf;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod expint;
+mod fixed;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod gamma;
+mod gaussian;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod hypergeometric;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod jacobi;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod lambert;
+mod overflow;
 mod pow;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod zeta;
 
 #[cfg(any(feature = "std", feature = "libm"))]
 mod complex_float;
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use crate::complex_float::ComplexFloat;
 
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use crate::branch::NthRoots;
+
+pub use crate::eisenstein::Eisenstein;
+
 #[cfg(feature = "rand")]
 mod crand;
 #[cfg(feature = "rand")]
 pub use crate::crand::ComplexDistribution;
 
-// FIXME #1284: handle complex NaN & infinity etc. This
-// probably doesn't map to C's _Complex correctly.
-
 /// A complex number in Cartesian form.
 ///
 /// ## Representation and Foreign Function Interface Compatibility
@@ -63,6 +95,23 @@ pub use crate::crand::ComplexDistribution;
 /// convention compatible.  This means that for FFI you can only pass
 /// `Complex<F>` behind a pointer, not as a value.
 ///
+/// ## Special Values
+///
+/// For floating point `T`, the elementary functions like `exp`, `ln`, `sqrt`,
+/// the trigonometric and hyperbolic functions, and their inverses handle
+/// infinite, NaN, and signed zero parts according to the special-value tables
+/// of C99 Annex G, so they match C's `<complex.h>`.  As there, a complex
+/// number with an infinite part is considered infinite even if the other part
+/// is NaN.
+///
+/// ## Branch Cuts
+///
+/// Also as in C99 Annex G, the sign of a zero part selects the side of a
+/// branch cut.  On a cut along the real axis, an imaginary part of `+0.0` is
+/// continuous with the upper half-plane, and `-0.0` with the lower.  On a cut
+/// along the imaginary axis, a real part of `+0.0` is continuous with the
+/// right half-plane, and `-0.0` with the left.
+///
 /// ## Examples
 ///
 /// Example of extern function declaration.
@@ -112,6 +161,9 @@ impl<T: Clone + Num> Complex<T> {
 
     /// Returns the square of the norm (since `T` doesn't necessarily
     /// have a sqrt function), i.e. `re^2 + im^2`.
+    ///
+    /// For primitive integers this overflows like `T`; see `checked_norm_sqr`
+    /// and, for signed types, `norm_sqr_wide`.
     #[inline]
     pub fn norm_sqr(&self) -> T {
         self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
@@ -174,9 +226,26 @@ impl<T: Clone + Signed> Complex<T> {
 impl<T: Float> Complex<T> {
     /// Create a new Complex with a given phase: `exp(i * phase)`.
     /// See [cis (mathematics)](https://en.wikipedia.org/wiki/Cis_(mathematics)).
+    ///
+    /// The phase is reduced by `T::sin_cos`, which for `f32` and `f64` reduces
+    /// it exactly, so the result is accurate for any finite phase, however
+    /// large.  Since `π` itself isn't representable, `cis(PI)` still has a tiny
+    /// imaginary part; see [`cis_pi`](Complex::cis_pi) for phases that are
+    /// multiples of `π`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // sin(10²²), which a reduction by an `f64` approximation of 2π gets wrong
+    /// let z = Complex64::cis(1e22);
+    /// assert!((z.im + 0.8522008497671888).abs() < 1e-15);
+    /// ```
     #[inline]
     pub fn cis(phase: T) -> Self {
-        Self::new(phase.cos(), phase.sin())
+        let (sin, cos) = phase.sin_cos();
+        Self::new(cos, sin)
     }
 
     /// Calculate |self|
@@ -184,6 +253,37 @@ impl<T: Float> Complex<T> {
     pub fn norm(self) -> T {
         self.re.hypot(self.im)
     }
+
+    /// Returns `(m, e)` such that `self.norm_sqr() == m * 2^e`, with `0.5 ≤ m < 1`,
+    /// like C's `frexp`.
+    ///
+    /// Unlike `norm_sqr`, this doesn't overflow or underflow for any finite `self`.
+    /// A zero or non-finite `self` returns `(self.norm_sqr(), 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(3e300, 4e300);
+    /// assert!(z.norm_sqr().is_infinite());
+    ///
+    /// // |z|² = 2.5e601 = 0.870980981621... * 2^1998
+    /// let (m, e) = z.norm_sqr_scaled();
+    /// assert_eq!(e, 1998);
+    /// assert!((m - 0.870980981621).abs() < 1e-12);
+    /// ```
+    pub fn norm_sqr_scaled(self) -> (T, i32) {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) || (re.is_zero() && im.is_zero()) {
+            return (self.norm_sqr(), 0);
+        }
+        // Scale the larger part into [1, 2), so the sum of squares is in [1, 8).
+        let k = ilogb(re.abs().max(im.abs()));
+        let (x, y) = (ldexp(re, -k), ldexp(im, -k));
+        let m = x * x + y * y;
+        let e = ilogb(m) + 1;
+        (ldexp(m, -e), 2 * k + e)
+    }
     /// Calculate the principal Arg of self.
     #[inline]
     pub fn arg(self) -> T {
@@ -196,9 +296,13 @@ impl<T: Float> Complex<T> {
         (self.norm(), self.arg())
     }
     /// Convert a polar representation into a complex number.
+    ///
+    /// As for [`cis`](Complex::cis), `theta` is reduced exactly for `f32` and
+    /// `f64`, however large.
     #[inline]
     pub fn from_polar(r: T, theta: T) -> Self {
-        Self::new(r * theta.cos(), r * theta.sin())
+        let (sin, cos) = theta.sin_cos();
+        Self::new(r * cos, r * sin)
     }
 
     /// Computes `e^(self)`, where `e` is the base of the natural logarithm.
@@ -221,34 +325,142 @@ impl<T: Float> Complex<T> {
             }
         } else if re.is_nan() && im == T::zero() {
             return self;
+        } else if im.is_zero() {
+            // Keep real results real, even if e^a overflows.
+            return Self::new(re.exp(), im);
         }
 
         Self::from_polar(re.exp(), im)
     }
 
+    /// Computes `e^(self) - 1`, accurately even when `self` is close to zero.
+    #[inline]
+    pub fn exp_m1(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return self.exp() - T::one();
+        } else if im.is_zero() {
+            return Self::new(re.exp_m1(), im);
+        }
+        // formula: Re(e^z - 1) = e^x cos(y) - 1 = expm1(x) cos(y) - 2 sin²(y/2)
+        let two = T::one() + T::one();
+        let s = (im / two).sin();
+        let re = re.exp_m1() * im.cos() - two * s * s;
+        Self::new(re, self.re.exp() * im.sin())
+    }
+
     /// Computes the principal value of natural logarithm of `self`.
     ///
     /// This function has one branch cut:
     ///
-    /// * `(-∞, 0]`, continuous from above.
+    /// * `(-∞, 0]`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π ≤ arg(ln(z)) ≤ π`.
     #[inline]
     pub fn ln(self) -> Self {
         // formula: ln(z) = ln|z| + i*arg(z)
-        let (r, theta) = self.to_polar();
-        Self::new(r.ln(), theta)
+        let Complex { re, im } = self;
+        if re.is_infinite() || im.is_infinite() {
+            // ln(±∞ + iNaN) = ln(NaN ± i∞) = +∞ + iNaN
+            let theta = if re.is_nan() || im.is_nan() {
+                T::nan()
+            } else {
+                im.atan2(re)
+            };
+            return Self::new(T::infinity(), theta);
+        }
+        let half = T::one() / (T::one() + T::one());
+        let (a, b) = (re.abs().max(im.abs()), re.abs().min(im.abs()));
+        let r2 = a * a + b * b;
+        let re = if half < r2 && r2 < half + half + half + half {
+            // Near the unit circle, `ln|z|` is tiny and `norm().ln()` loses all
+            // its relative accuracy, so compute `|z|² - 1` exactly instead.
+            let (a2, a2_err) = two_prod(a, a);
+            let (b2, b2_err) = two_prod(b, b);
+            sum2(&[-T::one(), a2, b2, a2_err, b2_err]).ln_1p() * half
+        } else {
+            // ln|z| = (ln(m) + e ln(2)) / 2, where |z|² = m 2^e can't overflow or underflow
+            let (m, e) = self.norm_sqr_scaled();
+            let ln_2 = (T::one() + T::one()).ln();
+            (m.ln() + T::from(e).unwrap() * ln_2) * half
+        };
+        Self::new(re, self.arg())
+    }
+
+    /// Computes the principal value of `ln(1 + self)`, accurately even when
+    /// `self` is close to zero.
+    ///
+    /// This function has one branch cut:
+    ///
+    /// * `(-∞, -1]`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
+    ///
+    /// The branch satisfies `-π ≤ arg(ln_1p(z)) ≤ π`.
+    #[inline]
+    pub fn ln_1p(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return (self + T::one()).ln();
+        } else if re.is_zero() && im.is_zero() {
+            return self;
+        }
+        let one = T::one();
+        let two = one + one;
+        let w = Self::new(one + re, im);
+        if re.abs().max(im.abs()) < two && w.norm_sqr() > one / two {
+            // formula: ln|1 + z| = ln_1p(2x + x² + y²) / 2, with the argument of
+            // `ln_1p` summed exactly so nothing cancels when `|1 + z| ≈ 1`
+            let (x2, x2_err) = two_prod(re, re);
+            let (y2, y2_err) = two_prod(im, im);
+            let d = sum2(&[two * re, x2, y2, x2_err, y2_err]);
+            // `1 + x` is exact whenever it is small enough to matter for `atan2`
+            Self::new(d.ln_1p() / two, im.atan2(w.re))
+        } else {
+            w.ln()
+        }
     }
 
     /// Computes the principal value of the square root of `self`.
     ///
     /// This function has one branch cut:
     ///
-    /// * `(-∞, 0)`, continuous from above.
+    /// * `(-∞, 0)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/2 ≤ arg(sqrt(z)) ≤ π/2`.
     #[inline]
     pub fn sqrt(self) -> Self {
+        let Complex { re, im } = self;
+        if im.is_infinite() {
+            // √(x ± i∞) = +∞ ± i∞, even if x is NaN
+            return Self::new(T::infinity(), im);
+        } else if re.is_infinite() {
+            let zero = T::zero();
+            return if im.is_nan() {
+                // √(-∞ + iNaN) = NaN ± i∞, √(+∞ + iNaN) = +∞ + iNaN
+                if re < zero {
+                    Self::new(im, T::infinity())
+                } else {
+                    Self::new(re, im)
+                }
+            } else if re < zero {
+                // √(-∞ ± iy) = +0 ± i∞
+                Self::new(zero, T::infinity().copysign(im))
+            } else {
+                // √(+∞ ± iy) = +∞ ± i0
+                Self::new(re, zero.copysign(im))
+            };
+        } else if re.is_nan() || im.is_nan() {
+            return Self::new(T::nan(), T::nan());
+        }
+
         if self.im.is_zero() {
             if self.re.is_sign_positive() {
                 // simple positive real √r, and copy `im` for its sign
@@ -264,23 +476,20 @@ impl<T: Float> Complex<T> {
                     Self::new(re, -im)
                 }
             }
-        } else if self.re.is_zero() {
-            // √(r e^(iπ/2)) = √r e^(iπ/4) = √(r/2) + i√(r/2)
-            // √(r e^(-iπ/2)) = √r e^(-iπ/4) = √(r/2) - i√(r/2)
-            let one = T::one();
-            let two = one + one;
-            let x = (self.im.abs() / two).sqrt();
-            if self.im.is_sign_positive() {
-                Self::new(x, x)
+        } else {
+            // Kahan's algorithm: with t = √((|x| + |z|) / 2),
+            // √z = t + iy/2t for x ≥ 0, and |y|/2t ± it for x < 0.
+            // Compute t from z scaled by an even power of two, so |z| can't
+            // overflow or underflow, and then the square root halves the exponent.
+            let two = T::one() + T::one();
+            let k = ilogb(re.abs().max(im.abs())) & !1;
+            let (x, y) = (ldexp(re, -k), ldexp(im, -k));
+            let t = ldexp(((x.abs() + x.hypot(y)) / two).sqrt(), k / 2);
+            if re.is_sign_positive() {
+                Self::new(t, im / (two * t))
             } else {
-                Self::new(x, -x)
+                Self::new(im.abs() / (two * t), t.copysign(im))
             }
-        } else {
-            // formula: sqrt(r e^(it)) = sqrt(r) e^(it/2)
-            let one = T::one();
-            let two = one + one;
-            let (r, theta) = self.to_polar();
-            Self::from_polar(r.sqrt(), theta / two)
         }
     }
 
@@ -288,7 +497,10 @@ impl<T: Float> Complex<T> {
     ///
     /// This function has one branch cut:
     ///
-    /// * `(-∞, 0)`, continuous from above.
+    /// * `(-∞, 0)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/3 ≤ arg(cbrt(z)) ≤ π/3`.
     ///
@@ -297,6 +509,26 @@ impl<T: Float> Complex<T> {
     /// but the principal complex cube root of `-8` is `1 + i√3`.
     #[inline]
     pub fn cbrt(self) -> Self {
+        // There is no C99 `ccbrt`, so special values follow those of `sqrt`.
+        let Complex { re, im } = self;
+        if im.is_infinite() {
+            // ∛(x ± i∞) = +∞ ± i∞, even if x is NaN
+            return Self::new(T::infinity(), im);
+        } else if re.is_infinite() {
+            return if im.is_nan() {
+                // ∛(±∞ + iNaN) = +∞ + iNaN
+                Self::new(T::infinity(), im)
+            } else if re < T::zero() {
+                // ∛(-∞ ± iy) = +∞ ± i∞
+                Self::new(T::infinity(), T::infinity().copysign(im))
+            } else {
+                // ∛(+∞ ± iy) = +∞ ± i0
+                Self::new(re, T::zero().copysign(im))
+            };
+        } else if re.is_nan() || im.is_nan() {
+            return Self::new(T::nan(), T::nan());
+        }
+
         if self.im.is_zero() {
             if self.re.is_sign_positive() {
                 // simple positive real ∛r, and copy `im` for its sign
@@ -372,6 +604,10 @@ impl<T: Float> Complex<T> {
     /// Raises a floating point number to the complex power `self`.
     #[inline]
     pub fn expf(self, base: T) -> Self {
+        if !(self.re.is_finite() && self.im.is_finite()) {
+            // scaling by ln(x) preserves the special values of `exp`
+            return self.scale(base.ln()).exp();
+        }
         // formula: x^(a+bi) = x^a x^bi = x^a e^(b ln(x) i)
         // = from_polar(x^a, b ln(x))
         Self::from_polar(base.powf(self.re), self.im * base.ln())
@@ -380,166 +616,575 @@ impl<T: Float> Complex<T> {
     /// Computes the sine of `self`.
     #[inline]
     pub fn sin(self) -> Self {
-        // formula: sin(a + bi) = sin(a)cosh(b) + i*cos(a)sinh(b)
-        Self::new(
-            self.re.sin() * self.im.cosh(),
-            self.re.cos() * self.im.sinh(),
-        )
+        // formula: sin(z) = -i sinh(iz)
+        self.mul_i().sinh().mul_neg_i()
     }
 
     /// Computes the cosine of `self`.
     #[inline]
     pub fn cos(self) -> Self {
-        // formula: cos(a + bi) = cos(a)cosh(b) - i*sin(a)sinh(b)
-        Self::new(
-            self.re.cos() * self.im.cosh(),
-            -self.re.sin() * self.im.sinh(),
-        )
+        // formula: cos(z) = cosh(iz)
+        self.mul_i().cosh()
     }
 
     /// Computes the tangent of `self`.
     #[inline]
     pub fn tan(self) -> Self {
-        // formula: tan(a + bi) = (sin(2a) + i*sinh(2b))/(cos(2a) + cosh(2b))
-        let (two_re, two_im) = (self.re + self.re, self.im + self.im);
-        Self::new(two_re.sin(), two_im.sinh()).unscale(two_re.cos() + two_im.cosh())
+        // formula: tan(z) = -i tanh(iz)
+        self.mul_i().tanh().mul_neg_i()
     }
 
     /// Computes the principal value of the inverse sine of `self`.
     ///
     /// This function has two branch cuts:
     ///
-    /// * `(-∞, -1)`, continuous from above.
-    /// * `(1, ∞)`, continuous from below.
+    /// * `(-∞, -1)`
+    /// * `(1, ∞)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/2 ≤ Re(asin(z)) ≤ π/2`.
+    ///
+    /// This goes through [`asinh`](Complex::asinh), and has the same accuracy.
     #[inline]
     pub fn asin(self) -> Self {
-        // formula: arcsin(z) = -i ln(sqrt(1-z^2) + iz)
-        let i = Self::i();
-        -i * ((Self::one() - self * self).sqrt() + i * self).ln()
+        // formula: arcsin(z) = -i arcsinh(iz)
+        self.mul_i().asinh().mul_neg_i()
     }
 
     /// Computes the principal value of the inverse cosine of `self`.
     ///
     /// This function has two branch cuts:
     ///
-    /// * `(-∞, -1)`, continuous from above.
-    /// * `(1, ∞)`, continuous from below.
+    /// * `(-∞, -1)`
+    /// * `(1, ∞)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `0 ≤ Re(acos(z)) ≤ π`.
+    ///
+    /// This uses the algorithm described for [`asinh`](Complex::asinh), and has
+    /// the same accuracy.
     #[inline]
     pub fn acos(self) -> Self {
-        // formula: arccos(z) = -i ln(i sqrt(1-z^2) + z)
-        let i = Self::i();
-        -i * (i * (Self::one() - self * self).sqrt() + self).ln()
+        let Complex { re, im } = self;
+        if re.is_infinite() || im.is_infinite() {
+            return if re.is_nan() {
+                // acos(NaN ± i∞) = NaN ∓ i∞
+                Self::new(re, -im)
+            } else if im.is_nan() {
+                // acos(±∞ + iNaN) = NaN ± i∞
+                Self::new(im, T::infinity())
+            } else {
+                // e.g. acos(-∞ ± iy) = π ∓ i∞, acos(+∞ ± i∞) = π/4 ∓ i∞
+                Self::new(im.abs().atan2(re), -T::infinity().copysign(im))
+            };
+        } else if re.is_zero() && (im.is_zero() || im.is_nan()) {
+            // acos(±0 ± i0) = π/2 ∓ i0, acos(±0 + iNaN) = π/2 + iNaN
+            return Self::new(T::one().atan2(T::zero()), -im);
+        } else if re.is_nan() || im.is_nan() {
+            return Self::new(T::nan(), T::nan());
+        }
+
+        // Hull, Fairgrieve and Tang's algorithm, as in FreeBSD's `cacos`:
+        // acos(x + iy) = acos(x / A) - i ln(A + √(A² - 1)), with A from `hft_kernel`
+        let one = T::one();
+        let (ax, ay) = (re.abs(), im.abs());
+        let (rx, ry) = if ax > one / T::epsilon() || ay > one / T::epsilon() {
+            (ay.atan2(re), Self::ln_large(ax.max(ay), ax.min(ay)))
+        } else if re == one && im.is_zero() {
+            (T::zero(), T::zero())
+        } else if ax.max(ay) < Self::hft_small() {
+            // acos(z) ≈ π/2 - z
+            (one.atan2(T::zero()) - re, ay)
+        } else {
+            let (ry, angle) = Self::hft_kernel(ay, ax);
+            let rx = match angle {
+                HftAngle::Sin(b) => b.copysign(re).acos(),
+                HftAngle::Tan(x, s) => s.atan2(x.copysign(re)),
+            };
+            (rx, ry)
+        };
+        Self::new(rx, -ry.copysign(im))
     }
 
     /// Computes the principal value of the inverse tangent of `self`.
     ///
     /// This function has two branch cuts:
     ///
-    /// * `(-∞i, -i]`, continuous from the left.
-    /// * `[i, ∞i)`, continuous from the right.
+    /// * `(-∞i, -i]`
+    /// * `[i, ∞i)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/2 ≤ Re(atan(z)) ≤ π/2`.
     #[inline]
     pub fn atan(self) -> Self {
-        // formula: arctan(z) = (ln(1+iz) - ln(1-iz))/(2i)
-        let i = Self::i();
-        let one = Self::one();
-        let two = one + one;
-        if self == i {
-            return Self::new(T::zero(), T::infinity());
-        } else if self == -i {
-            return Self::new(T::zero(), -T::infinity());
-        }
-        ((one + i * self).ln() - (one - i * self).ln()) / (two * i)
+        // formula: arctan(z) = -i arctanh(iz)
+        self.mul_i().atanh().mul_neg_i()
     }
 
     /// Computes the hyperbolic sine of `self`.
     #[inline]
     pub fn sinh(self) -> Self {
         // formula: sinh(a + bi) = sinh(a)cos(b) + i*cosh(a)sin(b)
-        Self::new(
-            self.re.sinh() * self.im.cos(),
-            self.re.cosh() * self.im.sin(),
-        )
+        let Complex { re, im } = self;
+        if re.is_finite() && im.is_finite() {
+            if im.is_zero() {
+                // Keep real results real, even if sinh(a) overflows.
+                return Self::new(re.sinh(), im);
+            }
+            return Self::new(re.sinh() * im.cos(), re.cosh() * im.sin());
+        }
+
+        if re.is_infinite() {
+            if im.is_zero() {
+                // sinh(±∞ ± i0) = ±∞ ± i0
+                self
+            } else if im.is_finite() {
+                // sinh(±∞ + iy) = ±∞ cos(y) + i∞ sin(y)
+                Self::new(re * im.cos(), T::infinity() * im.sin())
+            } else {
+                // sinh(±∞ + i∞) = sinh(±∞ + iNaN) = ±∞ + iNaN
+                Self::new(re, T::nan())
+            }
+        } else if re.is_zero() {
+            // sinh(±0 + i∞) = sinh(±0 + iNaN) = ±0 + iNaN
+            Self::new(re, T::nan())
+        } else if re.is_nan() && im.is_zero() {
+            // sinh(NaN ± i0) = NaN ± i0
+            self
+        } else {
+            Self::new(T::nan(), T::nan())
+        }
     }
 
     /// Computes the hyperbolic cosine of `self`.
     #[inline]
     pub fn cosh(self) -> Self {
         // formula: cosh(a + bi) = cosh(a)cos(b) + i*sinh(a)sin(b)
-        Self::new(
-            self.re.cosh() * self.im.cos(),
-            self.re.sinh() * self.im.sin(),
-        )
+        let Complex { re, im } = self;
+        let one = T::one();
+        if re.is_finite() && im.is_finite() {
+            if im.is_zero() {
+                // Keep real results real, even if cosh(a) overflows.
+                return Self::new(re.cosh(), im * one.copysign(re));
+            }
+            return Self::new(re.cosh() * im.cos(), re.sinh() * im.sin());
+        }
+
+        if re.is_infinite() {
+            if im.is_zero() {
+                // cosh(±∞ ± i0) = +∞ ± i0, with the product of the signs
+                Self::new(T::infinity(), im * one.copysign(re))
+            } else if im.is_finite() {
+                // cosh(±∞ + iy) = ∞ cos(y) ± i∞ sin(y)
+                Self::new(T::infinity() * im.cos(), re * im.sin())
+            } else {
+                // cosh(±∞ + i∞) = cosh(±∞ + iNaN) = +∞ + iNaN
+                Self::new(T::infinity(), T::nan())
+            }
+        } else if re.is_zero() || (re.is_nan() && im.is_zero()) {
+            // cosh(±0 + i∞) = cosh(±0 + iNaN) = cosh(NaN ± i0) = NaN ± i0
+            Self::new(T::nan(), T::zero())
+        } else {
+            Self::new(T::nan(), T::nan())
+        }
     }
 
     /// Computes the hyperbolic tangent of `self`.
     #[inline]
     pub fn tanh(self) -> Self {
-        // formula: tanh(a + bi) = (sinh(2a) + i*sin(2b))/(cosh(2a) + cos(2b))
-        let (two_re, two_im) = (self.re + self.re, self.im + self.im);
-        Self::new(two_re.sinh(), two_im.sin()).unscale(two_re.cosh() + two_im.cos())
+        let Complex { re, im } = self;
+        let one = T::one();
+        if re.is_finite() && im.is_finite() {
+            if im.is_zero() {
+                return Self::new(re.tanh(), im);
+            }
+            let two = one + one;
+            let four = two + two;
+            if re.abs() > (four / T::epsilon()).ln() / two {
+                // tanh(a) rounds to ±1, and cosh(2a) would overflow:
+                // tanh(a + bi) ≈ ±1 + 4i sin(b)cos(b)e^(-2|a|)
+                let e = (-re.abs()).exp();
+                return Self::new(one.copysign(re), four * im.sin() * im.cos() * e * e);
+            }
+            // Kahan's algorithm, from "Branch Cuts for Complex Elementary Functions":
+            // with t = tan(b), β = 1 + t², s = sinh(a), ρ = √(1 + s²),
+            // tanh(a + bi) = (βρs + it) / (1 + βs²)
+            let t = im.tan();
+            let beta = one + t * t;
+            let s = re.sinh();
+            let rho = (one + s * s).sqrt();
+            let denom = one + beta * s * s;
+            return Self::new(beta * rho * s / denom, t / denom);
+        }
+
+        if re.is_infinite() {
+            // tanh(±∞ + iy) = ±1 + i0 sin(2y), tanh(±∞ + i∞) = tanh(±∞ + iNaN) = ±1 ± i0
+            let im = if im.is_finite() {
+                T::zero() * (im.sin() * im.cos())
+            } else {
+                T::zero().copysign(im)
+            };
+            Self::new(one.copysign(re), im)
+        } else if re.is_nan() && im.is_zero() {
+            // tanh(NaN ± i0) = NaN ± i0
+            self
+        } else {
+            Self::new(T::nan(), T::nan())
+        }
     }
 
     /// Computes the principal value of inverse hyperbolic sine of `self`.
     ///
     /// This function has two branch cuts:
     ///
-    /// * `(-∞i, -i)`, continuous from the left.
-    /// * `(i, ∞i)`, continuous from the right.
+    /// * `(-∞i, -i)`
+    /// * `(i, ∞i)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/2 ≤ Im(asinh(z)) ≤ π/2`.
+    ///
+    /// This uses the algorithm of Hull, Fairgrieve and Tang, "Implementing the
+    /// Complex Arcsine and Arccosine Functions Using Exception Handling" (1997),
+    /// which is accurate near the branch points and doesn't overflow for large
+    /// arguments. Each part of the `f64` result is within 2 ulps of the
+    /// correctly rounded value.
     #[inline]
     pub fn asinh(self) -> Self {
-        // formula: arcsinh(z) = ln(z + sqrt(1+z^2))
-        let one = Self::one();
-        (self + (one + self * self).sqrt()).ln()
+        let Complex { re, im } = self;
+        if re.is_infinite() || im.is_infinite() {
+            return if re.is_nan() {
+                // asinh(NaN ± i∞) = ±∞ + iNaN
+                Self::new(T::infinity(), re)
+            } else if im.is_nan() {
+                // asinh(±∞ + iNaN) = ±∞ + iNaN
+                self
+            } else {
+                // e.g. asinh(±∞ + iy) = ±∞ + i0, asinh(±∞ + i∞) = ±∞ + iπ/4
+                Self::new(T::infinity().copysign(re), im.atan2(re.abs()))
+            };
+        } else if re.is_nan() || im.is_nan() {
+            return if im.is_zero() {
+                // asinh(NaN ± i0) = NaN ± i0
+                self
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        } else if self.is_zero() {
+            return self;
+        }
+
+        // Hull, Fairgrieve and Tang's algorithm, as in FreeBSD's `casinh`:
+        // asinh(x + iy) = ln(A + √(A² - 1)) + i asin(y / A), with A from `hft_kernel`
+        let one = T::one();
+        let (ax, ay) = (re.abs(), im.abs());
+        let (rx, ry) = if ax > one / T::epsilon() || ay > one / T::epsilon() {
+            (Self::ln_large(ax.max(ay), ax.min(ay)), ay.atan2(ax))
+        } else if ax.max(ay) < Self::hft_small() {
+            // asinh(z) ≈ z
+            (ax, ay)
+        } else {
+            let (rx, angle) = Self::hft_kernel(ax, ay);
+            let ry = match angle {
+                HftAngle::Sin(b) => b.asin(),
+                HftAngle::Tan(y, s) => y.atan2(s),
+            };
+            (rx, ry)
+        };
+        Self::new(rx.copysign(re), ry.copysign(im))
     }
 
     /// Computes the principal value of inverse hyperbolic cosine of `self`.
     ///
     /// This function has one branch cut:
     ///
-    /// * `(-∞, 1)`, continuous from above.
+    /// * `(-∞, 1)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π ≤ Im(acosh(z)) ≤ π` and `0 ≤ Re(acosh(z)) < ∞`.
+    ///
+    /// This goes through [`acos`](Complex::acos), and has the accuracy described
+    /// for [`asinh`](Complex::asinh).
     #[inline]
     pub fn acosh(self) -> Self {
-        // formula: arccosh(z) = 2 ln(sqrt((z+1)/2) + sqrt((z-1)/2))
-        let one = Self::one();
-        let two = one + one;
-        two * (((self + one) / two).sqrt() + ((self - one) / two).sqrt()).ln()
+        let Complex { re, im } = self;
+        if re.is_infinite() || im.is_infinite() {
+            return if re.is_nan() || im.is_nan() {
+                // acosh(±∞ + iNaN) = acosh(NaN ± i∞) = +∞ + iNaN
+                Self::new(T::infinity(), T::nan())
+            } else {
+                // e.g. acosh(-∞ ± iy) = +∞ ± iπ, acosh(x ± i∞) = +∞ ± iπ/2
+                Self::new(T::infinity(), im.atan2(re))
+            };
+        } else if re.is_nan() || im.is_nan() {
+            return Self::new(T::nan(), T::nan());
+        } else if self.is_zero() {
+            // acosh(±0 ± i0) = +0 ± iπ/2
+            return Self::new(T::zero(), T::one().atan2(T::zero()).copysign(im));
+        }
+
+        // formula: acosh(z) = ±i acos(z), with the sign chosen to keep Re(acosh(z)) ≥ 0
+        let w = self.acos();
+        Self::new(w.im.abs(), w.re.copysign(im))
     }
 
     /// Computes the principal value of inverse hyperbolic tangent of `self`.
     ///
     /// This function has two branch cuts:
     ///
-    /// * `(-∞, -1]`, continuous from above.
-    /// * `[1, ∞)`, continuous from below.
+    /// * `(-∞, -1]`
+    /// * `[1, ∞)`
+    ///
+    /// The sign of a zero part selects the side of a cut, as described under
+    /// [Branch Cuts](Complex#branch-cuts).
     ///
     /// The branch satisfies `-π/2 ≤ Im(atanh(z)) ≤ π/2`.
     #[inline]
     pub fn atanh(self) -> Self {
-        // formula: arctanh(z) = (ln(1+z) - ln(1-z))/2
-        let one = Self::one();
+        let Complex { re, im } = self;
+        if re.is_infinite() || im.is_infinite() {
+            // e.g. atanh(±∞ ± iy) = ±0 ± iπ/2, atanh(±∞ + iNaN) = ±0 + iNaN
+            let im = if im.is_nan() {
+                im
+            } else {
+                T::one().atan2(T::zero()).copysign(im)
+            };
+            return Self::new(T::zero().copysign(re), im);
+        } else if re.is_nan() || im.is_nan() {
+            return if re.is_zero() {
+                // atanh(±0 + iNaN) = ±0 + iNaN
+                self
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        } else if im.is_zero() && re.abs() == T::one() {
+            // atanh(±1 ± i0) = ±∞ ± i0
+            return Self::new(T::infinity().copysign(re), im);
+        } else if self.is_zero() {
+            return self;
+        }
+
+        // formula: arctanh(z) = (ln(1+z) - ln(1-z))/2, which separates into
+        // Re(arctanh(z)) = ln_1p(4x / ((1-x)^2 + y^2)) / 4
+        // Im(arctanh(z)) = atan2(2y, (1-x)(1+x) - y^2) / 2
+        // evaluated in the first quadrant, with the cases that would overflow or
+        // underflow split out as in FreeBSD's `catanh`.
+        let one = T::one();
+        let two = one + one;
+        let four = two + two;
+        let eps = T::epsilon();
+        let (ax, ay) = (re.abs(), im.abs());
+        let (rx, ry) = if ax > one / eps || ay > one / eps {
+            // arctanh(z) = arctanh(1/z) ± iπ/2, where arctanh(1/z) rounds to 1/z
+            (Self::new(ax, ay).finv().re, one.atan2(T::zero()))
+        } else if ax.max(ay) < ((two + one) * eps).sqrt() / two {
+            (ax, ay)
+        } else {
+            let rx = if ax == one && ay < eps {
+                // (1-x)^2 + y^2 = y^2 may underflow
+                (two.ln() - ay.ln()) / two
+            } else {
+                (four * ax / ((ax - one) * (ax - one) + ay * ay)).ln_1p() / four
+            };
+            let ry = if ax == one {
+                two.atan2(-ay) / two
+            } else if ay < eps {
+                (two * ay).atan2((one - ax) * (one + ax)) / two
+            } else {
+                (two * ay).atan2((one - ax) * (one + ax) - ay * ay) / two
+            };
+            (rx, ry)
+        };
+        // The sign of a zero y selects the side of the branch cuts.
+        Self::new(rx.copysign(re), ry.copysign(im))
+    }
+
+    /// Multiplies `self` by `i`, exactly and preserving signed zeros.
+    #[inline]
+    fn mul_i(self) -> Self {
+        Self::new(-self.im, self.re)
+    }
+
+    /// Multiplies `self` by `-i`, exactly and preserving signed zeros.
+    #[inline]
+    fn mul_neg_i(self) -> Self {
+        Self::new(self.im, -self.re)
+    }
+
+    /// Returns `ln|x + iy| + ln(2)` for `x ≥ y ≥ 0` and `x > 1/ε`, without overflow.
+    ///
+    /// This is the real part of `asinh(x + iy)` and `acosh(x + iy)` when `x` is so
+    /// large that `√(z² ± 1)` rounds to `z`.
+    #[inline]
+    fn ln_large(x: T, y: T) -> T {
+        let one = T::one();
+        let two = one + one;
+        let r = if x > T::max_value() / two {
+            let e = one.exp();
+            (x / e).hypot(y / e).ln() + one
+        } else {
+            x.hypot(y).ln()
+        };
+        r + two.ln()
+    }
+
+    /// Below `√(6ε)/4` in both parts, `asinh(z)` rounds to `z`, and `acos(z)` to `π/2 - z`.
+    #[inline]
+    fn hft_small() -> T {
+        let two = T::one() + T::one();
+        (two * (two + T::one()) * T::epsilon()).sqrt() / (two + two)
+    }
+
+    /// The core of Hull, Fairgrieve and Tang's algorithm for `asinh(x + iy)`, for finite
+    /// `x, y ≥ 0` that are neither tiny nor huge. The implementation follows FreeBSD's
+    /// `catrig.c`, where it is known as `do_hard_work`.
+    ///
+    /// With `R = |z + i|`, `S = |z - i|` and `A = (R + S) / 2`, the real part is
+    /// `ln(A + √(A² - 1))` and the imaginary part is `asin(y / A)`. Both are evaluated
+    /// without cancellation, and the latter is returned as an [`HftAngle`].
+    fn hft_kernel(x: T, y: T) -> (T, HftAngle<T>) {
+        let one = T::one();
         let two = one + one;
-        if self == one {
-            return Self::new(T::infinity(), T::zero());
-        } else if self == -one {
-            return Self::new(-T::infinity(), T::zero());
+        let four = two + two;
+        let eps = T::epsilon();
+        let tiny = eps / two.powi(7);
+        let a_crossover = four + four + two;
+        let b_crossover = T::from(0.6417).unwrap();
+        let four_sqrt_min = four * T::min_positive_value().sqrt();
+
+        // f(a, b, |a + ib|) = (|a + ib| - b) / 2, computed without cancellation.
+        let f = |a: T, b: T, h: T| {
+            if b < T::zero() {
+                (h - b) / two
+            } else if b.is_zero() {
+                a / two
+            } else {
+                a * a / (h + b) / two
+            }
+        };
+
+        let r = x.hypot(y + one);
+        let s = x.hypot(y - one);
+        let a = ((r + s) / two).max(one);
+
+        let rx = if a < a_crossover {
+            if y == one && x < eps * tiny {
+                x.sqrt()
+            } else if x >= eps * (y - one).abs() {
+                // A - 1 = f(x, 1 + y, R) + f(x, 1 - y, S)
+                let am1 = f(x, one + y, r) + f(x, one - y, s);
+                (am1 + (am1 * (a + one)).sqrt()).ln_1p()
+            } else if y < one {
+                x / ((one - y) * (one + y)).sqrt()
+            } else {
+                ((y - one) + ((y - one) * (y + one)).sqrt()).ln_1p()
+            }
+        } else {
+            (a + (a * a - one).sqrt()).ln()
+        };
+
+        if y < four_sqrt_min {
+            // Scale both terms up, so `atan2` doesn't see a subnormal `y`.
+            let k = two / eps;
+            return (rx, HftAngle::Tan(y * k, a * k));
+        }
+
+        let b = y / a;
+        if b <= b_crossover {
+            return (rx, HftAngle::Sin(b));
+        }
+
+        // asin(B) is ill-conditioned near 1, so use atan2(y, √(A² - y²)) instead.
+        let angle = if y == one && x < tiny {
+            HftAngle::Tan(y, x.sqrt() * ((a + y) / two).sqrt())
+        } else if x >= eps * (y - one).abs() {
+            // A - y = f(x, y + 1, R) + f(x, y - 1, S)
+            let amy = f(x, y + one, r) + f(x, y - one, s);
+            HftAngle::Tan(y, (amy * (a + y)).sqrt())
+        } else if y > one {
+            let k = four / eps / eps;
+            HftAngle::Tan(y * k, x * k * y / ((y + one) * (y - one)).sqrt())
+        } else {
+            HftAngle::Tan(y, ((one - y) * (one + y)).sqrt())
+        };
+        (rx, angle)
+    }
+
+    /// Returns `self * other`, using fused multiply-add to avoid cancellation.
+    ///
+    /// The generic `Mul` implementation computes `re * re - im * im`
+    /// directly, which loses all relative accuracy when the two products
+    /// nearly cancel.  This instead evaluates each part with Kahan's
+    /// algorithm for `ab - cd`, so each part of the result is accurate to
+    /// within 2 ulps, provided `T::mul_add` is fused and nothing overflows or
+    /// underflows.
+    ///
+    /// See: C.-P. Jeannerod, N. Louvet and J.-M. Muller, "Further analysis of
+    /// Kahan's algorithm for the accurate computation of 2×2 determinants",
+    /// Mathematics of Computation 82, 2013.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// let a = Complex64::new(0.6086544300013141, 1.3038230064600338);
+    /// let b = Complex64::new(1.0485333753688784, 0.4894793854383956);
+    ///
+    /// // The real part cancels completely with generic multiplication.
+    /// assert_eq!((a * b).re, 0.0);
+    ///
+    /// // But the fused product keeps it.
+    /// assert_eq!(a.mul_fma(b).re, -2.760403517225188e-18);
+    /// ```
+    #[inline]
+    pub fn mul_fma(self, other: Complex<T>) -> Complex<T> {
+        let Complex { re: a, im: b } = self;
+        let Complex { re: c, im: d } = other;
+        // formula: (a + bi)(c + di) = (ac - bd) + (ad - (-b)c)i
+        Self::new(kahan_det(a, c, b, d), kahan_det(a, d, -b, c))
+    }
+
+    /// Returns `self * other + add`, with every product computed exactly, so
+    /// that nothing cancels.
+    ///
+    /// This is a float-only alternative to the generic `MulAdd`
+    /// implementation, which fuses only one of the two products in each part.
+    /// Here, each part is summed from the exact products with a compensated
+    /// sum, so it is accurate to within a few ulps even when `add` cancels
+    /// most of `self * other`, provided `T::mul_add` is fused and nothing
+    /// overflows or underflows.
+    #[inline]
+    pub fn mul_add_fma(self, other: Complex<T>, add: Complex<T>) -> Complex<T> {
+        let Complex { re: a, im: b } = self;
+        let Complex { re: c, im: d } = other;
+        let (ac, ac_err) = two_prod(a, c);
+        let (bd, bd_err) = two_prod(b, d);
+        let (ad, ad_err) = two_prod(a, d);
+        let (bc, bc_err) = two_prod(b, c);
+        let re = sum2(&[ac, -bd, add.re, ac_err, -bd_err]);
+        let im = sum2(&[ad, bc, add.im, ad_err, bc_err]);
+        if re.is_finite() && im.is_finite() {
+            Self::new(re, im)
+        } else {
+            // The error terms are NaN if anything overflowed.
+            self * other + add
         }
-        ((one + self).ln() - (one - self).ln()) / two
     }
 
     /// Returns `1/self` using floating-point operations.
     ///
     /// This may be more accurate than the generic `self.inv()` in cases
     /// where `self.norm_sqr()` would overflow to ∞ or underflow to 0.
+    /// It is computed the same way as [`fdiv`](Complex::fdiv).
     ///
     /// # Examples
     ///
@@ -560,14 +1205,24 @@ impl<T: Float> Complex<T> {
     /// ```
     #[inline]
     pub fn finv(self) -> Complex<T> {
-        let norm = self.norm();
-        self.conj() / norm / norm
+        Self::one().fdiv(self)
     }
 
     /// Returns `self/other` using floating-point operations.
     ///
-    /// This may be more accurate than the generic `Div` implementation in cases
-    /// where `other.norm_sqr()` would overflow to ∞ or underflow to 0.
+    /// This uses the robust variant of Smith's algorithm by Baudin and Smith,
+    /// which rescales the operands by powers of two when they are near the
+    /// limits of the exponent range, so the quotient does not overflow or
+    /// underflow unless the result itself does.  Each part of the result is
+    /// accurate to a few ulps over the whole exponent range, unlike the
+    /// generic `Div` implementation which fails in cases where
+    /// `other.norm_sqr()` would overflow to ∞ or underflow to 0.
+    ///
+    /// Infinite and zero operands follow the recovery rules of C99 Annex G,
+    /// so for example a finite value divided by zero is infinite, not NaN.
+    ///
+    /// See: M. Baudin and R. L. Smith, "A Robust Complex Division in Scilab",
+    /// arXiv:1210.4539, 2012.
     ///
     /// # Examples
     ///
@@ -589,7 +1244,215 @@ impl<T: Float> Complex<T> {
     /// ```
     #[inline]
     pub fn fdiv(self, other: Complex<T>) -> Complex<T> {
-        self * other.finv()
+        let Complex {
+            re: mut a,
+            im: mut b,
+        } = self;
+        let Complex {
+            re: mut c,
+            im: mut d,
+        } = other;
+        let one = T::one();
+        let two = one + one;
+
+        // Scale the operands away from the overflow and underflow thresholds,
+        // keeping track of the power-of-two factor to apply to the result.
+        let ab = a.abs().max(b.abs());
+        let cd = c.abs().max(d.abs());
+        let half_max = T::max_value() / two;
+        let tiny = T::min_positive_value() * two / T::epsilon();
+        let big = two / (T::epsilon() * T::epsilon());
+        let mut s = one;
+        if ab >= half_max {
+            a = a / two;
+            b = b / two;
+            s = s * two;
+        }
+        if cd >= half_max {
+            c = c / two;
+            d = d / two;
+            s = s / two;
+        }
+        if ab <= tiny {
+            a = a * big;
+            b = b * big;
+            s = s / big;
+        }
+        if cd <= tiny {
+            c = c * big;
+            d = d * big;
+            s = s * big;
+        }
+
+        let (re, im) = if d.abs() <= c.abs() {
+            Self::smith_div(a, b, c, d)
+        } else {
+            let (re, im) = Self::smith_div(b, a, d, c);
+            (re, -im)
+        };
+        let (re, im) = (re * s, im * s);
+
+        if re.is_nan() && im.is_nan() {
+            // Recover infinities and zeros that the algorithm turned into NaN.
+            Self::fdiv_special(self, other).unwrap_or(Self::new(re, im))
+        } else {
+            Self::new(re, im)
+        }
+    }
+
+    /// Smith's division `(a + i b) / (c + i d)` for `|d| <= |c|`, with the
+    /// Baudin-Smith refinements for when `d / c` or its products underflow.
+    #[inline]
+    fn smith_div(a: T, b: T, c: T, d: T) -> (T, T) {
+        let r = d / c;
+        let t = (c + d * r).recip();
+        let part = |a: T, b: T| {
+            if !r.is_zero() {
+                let br = b * r;
+                if !br.is_zero() {
+                    (a + br) * t
+                } else {
+                    a * t + (b * t) * r
+                }
+            } else {
+                (a + d * (b / c)) * t
+            }
+        };
+        (part(a, b), part(b, -a))
+    }
+
+    /// The special cases of C99 Annex G, for when `fdiv` produced `NaN + NaN i`.
+    fn fdiv_special(self, other: Complex<T>) -> Option<Complex<T>> {
+        let Complex { re: a, im: b } = self;
+        let Complex { re: c, im: d } = other;
+        let (zero, one) = (T::zero(), T::one());
+        let unit = |x: T| (if x.is_infinite() { one } else { zero }).copysign(x);
+
+        if other.is_zero() && (!a.is_nan() || !b.is_nan()) {
+            let inf = T::infinity().copysign(c);
+            Some(Self::new(inf * a, inf * b))
+        } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+            let (a, b) = (unit(a), unit(b));
+            let inf = T::infinity();
+            Some(Self::new(inf * (a * c + b * d), inf * (b * c - a * d)))
+        } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+            let (c, d) = (unit(c), unit(d));
+            Some(Self::new(zero * (a * c + b * d), zero * (b * c - a * d)))
+        } else {
+            None
+        }
+    }
+}
+
+/// The imaginary part of `asinh` from [`Complex::hft_kernel`], either as the sine
+/// of the angle or as the two arguments of `atan2`.
+#[cfg(any(feature = "std", feature = "libm"))]
+enum HftAngle<T> {
+    Sin(T),
+    Tan(T, T),
+}
+
+/// Returns the exponent `k` with `1 ≤ |x| / 2^k < 2`, like C's `ilogb`, for
+/// finite nonzero `x`, including subnormals.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn ilogb<T: Float>(x: T) -> i32 {
+    let (mantissa, exponent, _) = x.integer_decode();
+    i32::from(exponent) + 63 - mantissa.leading_zeros() as i32
+}
+
+/// Returns `x * 2^e`, in two steps so the power of two itself can't overflow
+/// or underflow.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn ldexp<T: Float>(x: T, e: i32) -> T {
+    let two = T::one() + T::one();
+    let h = e / 2;
+    x * two.powi(h) * two.powi(e - h)
+}
+
+/// Converts an `f64` constant to `T`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn constant<T: Float>(c: f64) -> T {
+    T::from(c).unwrap()
+}
+
+/// Evaluates the polynomial with `coeffs`, highest degree first, at `z`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn horner<T: Float>(coeffs: &[f64], z: Complex<T>) -> Complex<T> {
+    coeffs.iter().fold(Complex::zero(), |acc, &c| {
+        acc * z + Complex::from(constant::<T>(c))
+    })
+}
+
+/// Returns `(sin(πx), cos(πx))`, with the argument reduced exactly.
+///
+/// Following IEEE 754, `sin(πn)` is a zero with the sign of `n`, and
+/// `cos(π(n + 1/2))` is `+0`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn sin_cos_pi<T: Float + FloatConst>(x: T) -> (T, T) {
+    if !x.is_finite() {
+        return (T::nan(), T::nan());
+    }
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let four = two + two;
+    let half = one / two;
+    let quarter = half / two;
+
+    // From 2/ε on, every float is an even integer, and doubling it could
+    // overflow.
+    if x.abs() >= two / T::epsilon() {
+        return (zero.copysign(x), one);
+    }
+
+    // x = n/2 + f, with n an integer and |f| ≤ 1/4. Every step is exact: for
+    // large x, `x * 2` is already an integer and f is zero.
+    let n = (x * two).round();
+    let f = x - n * half;
+    let (s, c) = if f.abs() == quarter {
+        let h = half.sqrt();
+        (h.copysign(f), h)
+    } else if f.is_zero() {
+        (f, one)
+    } else {
+        (T::PI() * f).sin_cos()
+    };
+
+    // rotate by n quarter-turns
+    let q = n - four * (n / four).floor();
+    let (sin, cos) = if q == zero {
+        (s, c)
+    } else if q == one {
+        (c, -s)
+    } else if q == two {
+        (-s, -c)
+    } else {
+        (-c, s)
+    };
+    let sin = if sin.is_zero() { zero.copysign(x) } else { sin };
+    let cos = if cos.is_zero() { zero } else { cos };
+    (sin, cos)
+}
+
+/// Returns `ab - cd` with Kahan's algorithm, which is accurate to within 2 ulps
+/// even when the products nearly cancel, provided `T::mul_add` is fused.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn kahan_det<T: Float>(a: T, b: T, c: T, d: T) -> T {
+    let w = c * d;
+    let e = (-c).mul_add(d, w);
+    let f = a.mul_add(b, -w);
+    let r = f + e;
+    if r.is_finite() {
+        r
+    } else {
+        // The error term is NaN if `c * d` overflowed.
+        a * b - w
     }
 }
 
@@ -598,11 +1461,97 @@ impl<T: Float + FloatConst> Complex<T> {
     /// Computes `2^(self)`.
     #[inline]
     pub fn exp2(self) -> Self {
+        if !(self.re.is_finite() && self.im.is_finite()) {
+            // scaling by ln(2) preserves the special values of `exp`
+            return self.scale(T::LN_2()).exp();
+        }
         // formula: 2^(a + bi) = 2^a (cos(b*log2) + i*sin(b*log2))
         // = from_polar(2^a, b*log2)
         Self::from_polar(self.re.exp2(), self.im * T::LN_2())
     }
 
+    /// Create a new Complex with a phase given in half-turns: `exp(iπx)`.
+    ///
+    /// The reduction of `x` is exact for any finite `x`, so the result is exact
+    /// whenever `x` is a multiple of `1/2`, and both parts are the correctly
+    /// rounded `√2/2` in magnitude when `x` is an odd multiple of `1/4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// assert_eq!(Complex64::cis_pi(1.0), Complex64::new(-1.0, 0.0));
+    /// assert_eq!(Complex64::cis_pi(-0.5), Complex64::new(0.0, -1.0));
+    /// assert_eq!(Complex64::cis_pi(1e300), Complex64::new(1.0, 0.0));
+    ///
+    /// let z = Complex64::cis_pi(0.25);
+    /// assert_eq!(z.re, z.im);
+    /// ```
+    #[inline]
+    pub fn cis_pi(x: T) -> Self {
+        let (sin, cos) = sin_cos_pi(x);
+        Self::new(cos, sin)
+    }
+
+    /// Computes `exp(iπ * self)`.
+    ///
+    /// This is `exp(-π * self.im) * cis_pi(self.re)`, so it has the same exact
+    /// values as [`cis_pi`](Complex::cis_pi) on the real axis.
+    #[inline]
+    pub fn exp_i_pi(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return self.scale(T::PI()).mul_i().exp();
+        }
+        let (sin, cos) = sin_cos_pi(re);
+        let m = (-T::PI() * im).exp();
+        // Keep exact zeros, even if `m` overflows.
+        let re = if cos.is_zero() { cos } else { m * cos };
+        let im = if sin.is_zero() { sin } else { m * sin };
+        Self::new(re, im)
+    }
+
+    /// Computes `sin(π * self)`, with the same exact values as
+    /// [`cis_pi`](Complex::cis_pi) on the real axis.
+    #[inline]
+    pub fn sin_pi(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return self.scale(T::PI()).sin();
+        }
+        // formula: sin(π(a+bi)) = sin(πa)cosh(πb) + i cos(πa)sinh(πb)
+        let (sin, cos) = sin_cos_pi(re);
+        let b = T::PI() * im;
+        let re = if sin.is_zero() { sin } else { sin * b.cosh() };
+        let im = if cos.is_zero() || im.is_zero() {
+            cos * im
+        } else {
+            cos * b.sinh()
+        };
+        Self::new(re, im)
+    }
+
+    /// Computes `cos(π * self)`, with the same exact values as
+    /// [`cis_pi`](Complex::cis_pi) on the real axis.
+    #[inline]
+    pub fn cos_pi(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return self.scale(T::PI()).cos();
+        }
+        // formula: cos(π(a+bi)) = cos(πa)cosh(πb) - i sin(πa)sinh(πb)
+        let (sin, cos) = sin_cos_pi(re);
+        let b = T::PI() * im;
+        let re = if cos.is_zero() { cos } else { cos * b.cosh() };
+        let im = if sin.is_zero() || im.is_zero() {
+            -(sin * im)
+        } else {
+            -(sin * b.sinh())
+        };
+        Self::new(re, im)
+    }
+
     /// Computes the principal value of log base 2 of `self`.
     #[inline]
     pub fn log2(self) -> Self {
@@ -624,9 +1573,12 @@ impl<T: FloatCore> Complex<T> {
     }
 
     /// Checks if the given complex number is infinite
+    ///
+    /// As in C99 Annex G, a complex number is infinite if either part is
+    /// infinite, even if the other part is NaN.
     #[inline]
     pub fn is_infinite(self) -> bool {
-        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+        self.re.is_infinite() || self.im.is_infinite()
     }
 
     /// Checks if the given complex number is finite
@@ -661,7 +1613,7 @@ impl<T: Clone + Num> From<T> for Complex<T> {
     }
 }
 
-impl<'a, T: Clone + Num> From<&'a T> for Complex<T> {
+impl<T: Clone + Num> From<&T> for Complex<T> {
     #[inline]
     fn from(re: &T) -> Self {
         From::from(re.clone())
@@ -743,6 +1695,9 @@ impl<T: Clone + Num> Sub<Complex<T>> for Complex<T> {
 forward_all_binop!(impl Mul, mul);
 
 // (a + i b) * (c + i d) == (a*c - b*d) + i (a*d + b*c)
+//
+// This is exact for integers, but floating-point types should prefer
+// `Complex::mul_fma` when `a*c` and `b*d` may nearly cancel.
 impl<T: Clone + Num> Mul<Complex<T>> for Complex<T> {
     type Output = Self;
 
@@ -766,7 +1721,7 @@ impl<T: Clone + Num + MulAdd<Output = T>> MulAdd<Complex<T>> for Complex<T> {
         Complex::new(re, im)
     }
 }
-impl<'a, 'b, T: Clone + Num + MulAdd<Output = T>> MulAdd<&'b Complex<T>> for &'a Complex<T> {
+impl<T: Clone + Num + MulAdd<Output = T>> MulAdd<&Complex<T>> for &Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
@@ -779,6 +1734,9 @@ forward_all_binop!(impl Div, div);
 
 // (a + i b) / (c + i d) == [(a + i b) * (c - i d)] / (c*c + d*d)
 //   == [(a*c + b*d) / (c*c + d*d)] + i [(b*c - a*d) / (c*c + d*d)]
+//
+// This is exact for integers, but floating-point types should prefer
+// `Complex::fdiv` to avoid overflow and underflow in `c*c + d*d`.
 impl<T: Clone + Num> Div<Complex<T>> for Complex<T> {
     type Output = Self;
 
@@ -799,6 +1757,29 @@ impl<T: Clone + Num> Complex<T> {
         let Complex { re, im } = self / divisor;
         Complex::new(re.clone() - re % T::one(), im.clone() - im % T::one())
     }
+
+    /// Returns the quotient rounded towards zero in each component, and the
+    /// remainder, `self - divisor * quotient`, as given by `%`.
+    ///
+    /// For integer types, the quotient is also that of `/`.  The remainder may
+    /// have a norm as large as the divisor's, or larger, so the Euclidean
+    /// algorithm should use [`div_round`](Complex::div_round) or
+    /// [`Euclid`](num_traits::Euclid) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let (q, r) = Complex::new(7i32, 5).div_rem(&Complex::new(2, 1));
+    /// assert_eq!((q, r), (Complex::new(3, 0), Complex::new(1, 2)));
+    /// ```
+    #[inline]
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let quotient = self.div_trunc(divisor);
+        let remainder = self.clone() - divisor.clone() * quotient.clone();
+        (quotient, remainder)
+    }
 }
 
 impl<T: Clone + Num> Rem<Complex<T>> for Complex<T> {
@@ -861,9 +1842,7 @@ mod opassign {
         }
     }
 
-    impl<'a, 'b, T: Clone + NumAssign + MulAddAssign> MulAddAssign<&'a Complex<T>, &'b Complex<T>>
-        for Complex<T>
-    {
+    impl<T: Clone + NumAssign + MulAddAssign> MulAddAssign<&Complex<T>, &Complex<T>> for Complex<T> {
         fn mul_add_assign(&mut self, other: &Complex<T>, add: &Complex<T>) {
             self.mul_add_assign(other.clone(), add.clone());
         }
@@ -959,7 +1938,7 @@ impl<T: Clone + Num + Neg<Output = T>> Neg for Complex<T> {
     }
 }
 
-impl<'a, T: Clone + Num + Neg<Output = T>> Neg for &'a Complex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Neg for &Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
@@ -973,11 +1952,11 @@ impl<T: Clone + Num + Neg<Output = T>> Inv for Complex<T> {
 
     #[inline]
     fn inv(self) -> Self::Output {
-        (&self).inv()
+        Complex::inv(&self)
     }
 }
 
-impl<'a, T: Clone + Num + Neg<Output = T>> Inv for &'a Complex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Inv for &Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
@@ -1039,6 +2018,10 @@ macro_rules! real_arithmetic {
             }
         )*
     );
+    // Floats negate directly to keep the sign of zero, which `0 - x` loses.
+    (@neg f32, $x:expr) => (-$x);
+    (@neg f64, $x:expr) => (-$x);
+    (@neg $real:ident, $x:expr) => ($real::zero() - $x);
     ($($real:ident),*) => (
         real_arithmetic!(@forward Add::add for $($real),*);
         real_arithmetic!(@forward Sub::sub for $($real),*);
@@ -1061,7 +2044,7 @@ macro_rules! real_arithmetic {
 
                 #[inline]
                 fn sub(self, other: Complex<$real>) -> Self::Output  {
-                    Self::Output::new(self - other.re, $real::zero() - other.im)
+                    Self::Output::new(self - other.re, real_arithmetic!(@neg $real, other.im))
                 }
             }
 
@@ -1082,7 +2065,7 @@ macro_rules! real_arithmetic {
                     // a / (c + i d) == [a * (c - i d)] / (c*c + d*d)
                     let norm_sqr = other.norm_sqr();
                     Self::Output::new(self * other.re / norm_sqr.clone(),
-                                      $real::zero() - self * other.im / norm_sqr)
+                                      real_arithmetic!(@neg $real, self * other.im / norm_sqr))
                 }
             }
 
@@ -1183,7 +2166,10 @@ impl<T: Clone + Num> One for Complex<T> {
 }
 
 macro_rules! write_complex {
-    ($f:ident, $t:expr, $prefix:expr, $re:expr, $im:expr, $T:ident) => {{
+    ($f:ident, $t:expr, $prefix:expr, $re:expr, $im:expr, $T:ident) => {
+        write_complex!($f, $t, $prefix, $re, $im, $T, "i")
+    };
+    ($f:ident, $t:expr, $prefix:expr, $re:expr, $im:expr, $T:ident, $unit:expr) => {{
         let abs_re = if $re < Zero::zero() {
             $T::zero() - $re.clone()
         } else {
@@ -1233,7 +2219,7 @@ macro_rules! write_complex {
                 fmt_complex(
                     f,
                     format_args!(
-                        "{}{pre}{re}-{pre}{im}i",
+                        concat!("{}{pre}{re}-{pre}{im}", $unit),
                         sign,
                         re = real,
                         im = imag,
@@ -1244,7 +2230,7 @@ macro_rules! write_complex {
                 fmt_complex(
                     f,
                     format_args!(
-                        "{}{pre}{re}+{pre}{im}i",
+                        concat!("{}{pre}{re}+{pre}{im}", $unit),
                         sign,
                         re = real,
                         im = imag,
@@ -1272,6 +2258,8 @@ macro_rules! write_complex {
     }};
 }
 
+pub(crate) use write_complex;
+
 // string conversions
 impl<T> fmt::Display for Complex<T>
 where
@@ -1336,15 +2324,31 @@ where
     }
 }
 
-#[allow(deprecated)] // `trim_left_matches` and `trim_right_matches` since 1.33
 fn from_str_generic<T, E, F>(s: &str, from: F) -> Result<Complex<T>, ParseComplexError<E>>
 where
     F: Fn(&str) -> Result<T, E>,
     T: Clone + Num,
 {
-    let imag = match s.rfind('j') {
-        None => 'i',
-        _ => 'j',
+    let (re, im) = parse_parts(s, ['i', 'j'], from)?;
+    Ok(Complex::new(re, im))
+}
+
+/// Parses `a +/- bu`; `bu +/- a`; `a`; or `bu`, where `u` is either of the
+/// `units`, preferring the second if it appears at all, and returns `(a, b)`.
+#[allow(deprecated)] // `trim_left_matches` and `trim_right_matches` since 1.33
+pub(crate) fn parse_parts<T, E, F>(
+    s: &str,
+    units: [char; 2],
+    from: F,
+) -> Result<(T, T), ParseComplexError<E>>
+where
+    F: Fn(&str) -> Result<T, E>,
+    T: Clone + Num,
+{
+    let imag = if s.contains(units[1]) {
+        units[1]
+    } else {
+        units[0]
     };
 
     let mut neg_b = false;
@@ -1358,8 +2362,8 @@ where
         // ignore '+'/'-' if part of an exponent
         if (c == b'+' || c == b'-') && !(p == b'e' || p == b'E') {
             // trim whitespace around the separator
-            a = &s[..=i].trim_right_matches(char::is_whitespace);
-            b = &s[i + 2..].trim_left_matches(char::is_whitespace);
+            a = s[..=i].trim_right_matches(char::is_whitespace);
+            b = s[i + 2..].trim_left_matches(char::is_whitespace);
             neg_b = c == b'-';
 
             if b.is_empty() || (neg_b && b.starts_with('-')) {
@@ -1369,36 +2373,25 @@ where
         }
     }
 
-    // split off real and imaginary parts
-    if b.is_empty() {
+    // split off real and imaginary parts, and pop the imaginary unit off
+    let (re, neg_re, mut im, neg_im) = if b.is_empty() {
         // input was either pure real or pure imaginary
-        b = if a.ends_with(imag) { "0" } else { "0i" };
-    }
-
-    let re;
-    let neg_re;
-    let im;
-    let neg_im;
-    if a.ends_with(imag) {
-        im = a;
-        neg_im = false;
-        re = b;
-        neg_re = neg_b;
-    } else if b.ends_with(imag) {
-        re = a;
-        neg_re = false;
-        im = b;
-        neg_im = neg_b;
+        match a.strip_suffix(imag) {
+            Some(im) => ("0", false, im, false),
+            None => (a, false, "0", false),
+        }
+    } else if let Some(im) = a.strip_suffix(imag) {
+        (b, neg_b, im, false)
+    } else if let Some(im) = b.strip_suffix(imag) {
+        (a, false, im, neg_b)
     } else {
         return Err(ParseComplexError::expr_error());
-    }
+    };
 
     // parse re
     let re = from(re).map_err(ParseComplexError::from_error)?;
     let re = if neg_re { T::zero() - re } else { re };
 
-    // pop imaginary unit off
-    let mut im = &im[..im.len() - 1];
     // handle im == "i" or im == "-i"
     if im.is_empty() || im == "+" {
         im = "1";
@@ -1410,7 +2403,7 @@ where
     let im = from(im).map_err(ParseComplexError::from_error)?;
     let im = if neg_im { T::zero() - im } else { im };
 
-    Ok(Complex::new(re, im))
+    Ok((re, im))
 }
 
 impl<T> FromStr for Complex<T>
@@ -1706,8 +2699,6 @@ pub(crate) mod test {
     #[cfg(any(feature = "std", feature = "libm"))]
     pub(crate) mod float {
 
-        use core::f64::INFINITY;
-
         use super::*;
         use num_traits::{Float, Pow};
 
@@ -1718,6 +2709,90 @@ pub(crate) mod test {
             assert!(close(Complex::cis(1.0 * f64::consts::PI), -_1_0i));
             assert!(close(Complex::cis(1.5 * f64::consts::PI), -_0_1i));
             assert!(close(Complex::cis(2.0 * f64::consts::PI), _1_0i));
+
+            // large phases, reduced exactly, with references from mpmath
+            let cases = [
+                (1e22, (0.523214785395139, -0.8522008497671888)),
+                (-1e22, (0.523214785395139, 0.8522008497671888)),
+                (1e300, (-0.5753861119575491, -0.8178819121159085)),
+                (f64::MAX, (-0.9999876894265599, 0.004961954789184062)),
+            ];
+            for &(theta, (re, im)) in cases.iter() {
+                assert!(close_ulps(Complex::cis(theta), Complex::new(re, im), 1));
+            }
+            let z = Complex::from_polar(2.5, 1e22);
+            let expected = Complex::new(1.3080369634878473, -2.130502124417972);
+            assert!(close_ulps(z, expected, 2));
+            let z = Complex::<f32>::cis(3e38);
+            assert!((z.re + 0.4842948).abs() < 1e-7 && (z.im - 0.8749049).abs() < 1e-7);
+        }
+
+        #[test]
+        fn test_cis_pi() {
+            let h = 0.5f64.sqrt();
+            let expected = [
+                _1_0i,
+                Complex::new(h, h),
+                _0_1i,
+                Complex::new(-h, h),
+                -_1_0i,
+                Complex::new(-h, -h),
+                -_0_1i,
+                Complex::new(h, -h),
+            ];
+            for k in -16i32..=16 {
+                let z = Complex::cis_pi(f64::from(k) / 4.0);
+                assert_eq!(z, expected[k.rem_euclid(8) as usize]);
+            }
+            // Huge arguments are even integers, and 2^51 + 1/2 is representable.
+            assert_eq!(Complex::cis_pi(1e300), _1_0i);
+            assert_eq!(Complex::cis_pi(-1e300), _1_0i);
+            assert_eq!(Complex::cis_pi(f64::MAX), _1_0i);
+            assert!(Complex::cis_pi(-f64::MAX).im.is_sign_negative());
+            assert_eq!(Complex::cis_pi(3e38f32), Complex::new(1.0, 0.0));
+            assert_eq!(Complex::cis_pi(2f64.powi(52) + 1.0), -_1_0i);
+            assert_eq!(Complex::cis_pi(2f64.powi(51) + 0.5), _0_1i);
+            assert_eq!(Complex::cis_pi(1e15 + 0.25), Complex::new(h, h));
+            assert!(close(
+                Complex::cis_pi(1.0 / 3.0),
+                Complex::cis(f64::consts::PI / 3.0)
+            ));
+            assert!(close(Complex::cis_pi(1e6 + 0.1), Complex::cis_pi(0.1)));
+
+            // signed zeros follow IEEE 754 sinPi and cosPi
+            assert!(Complex::cis_pi(-0.0).im.is_sign_negative());
+            assert!(Complex::cis_pi(-2.0).im.is_sign_negative());
+            assert!(Complex::cis_pi(3.0).im.is_sign_positive());
+            assert!(Complex::cis_pi(-0.5).re.is_sign_positive());
+            assert!(Complex::cis_pi(f64::INFINITY).is_nan());
+        }
+
+        #[test]
+        fn test_sin_cos_exp_pi() {
+            let pi = f64::consts::PI;
+            for &c in all_consts.iter() {
+                assert!(close(c.sin_pi(), c.scale(pi).sin()));
+                assert!(close(c.cos_pi(), c.scale(pi).cos()));
+                assert!(close(c.exp_i_pi(), (c * _0_1i).scale(pi).exp()));
+            }
+            for k in -8..=8 {
+                let x = Complex::new(f64::from(k) / 2.0, 0.0);
+                let z = Complex::cis_pi(x.re);
+                assert_eq!(x.exp_i_pi(), z);
+                assert_eq!(x.cos_pi().re, z.re);
+                assert_eq!(x.sin_pi().re, z.im);
+            }
+            // Exact zeros survive an overflowing cosh.
+            let z = Complex::new(0.5, 300.0).cos_pi();
+            assert_eq!(z.re, 0.0);
+            assert!(z.im.is_infinite());
+            let z = Complex::new(0.5, -300.0).exp_i_pi();
+            assert_eq!(z, Complex::new(0.0, f64::INFINITY));
+
+            let nan = Complex::new(f64::NAN, 0.0);
+            assert!(nan.sin_pi().is_nan() && nan.cos_pi().is_nan());
+            let inf = Complex::new(0.0, f64::INFINITY);
+            assert!(close_naninf(inf.cos_pi(), inf.scale(pi).cos()));
         }
 
         #[test]
@@ -1758,11 +2833,197 @@ pub(crate) mod test {
             }
         }
 
+        // Distance between two floats in units in the last place.
+        pub(crate) fn ulps(a: f64, b: f64) -> u64 {
+            if a == b {
+                return 0;
+            }
+            if a.is_nan() || b.is_nan() || a.is_sign_positive() != b.is_sign_positive() {
+                return u64::MAX;
+            }
+            let (a, b) = (a.abs().to_bits(), b.abs().to_bits());
+            a.max(b) - a.min(b)
+        }
+
+        pub(crate) fn close_ulps(a: Complex64, b: Complex64, tol: u64) -> bool {
+            let close = ulps(a.re, b.re) <= tol && ulps(a.im, b.im) <= tol;
+            if !close {
+                println!("{:e} != {:e}", a, b);
+            }
+            close
+        }
+
+        #[test]
+        fn test_mul_fma() {
+            // (a, b, a * b) with a nearly cancelling real part, rounded from exact products
+            let cases = [
+                (
+                    (0.9857491472497435, 0.7262737608867529),
+                    (1.4764017095597806, 2.003874853498322),
+                    (-8.33573858405422e-17, 3.0475897502127887),
+                ),
+                (
+                    (0.6086544300013141, 1.3038230064600338),
+                    (1.0485333753688784, 0.4894793854383956),
+                    (-2.760403517225188e-18, 1.6650257341885384),
+                ),
+                (
+                    (0.5869983871620602, 1.2611535997841303),
+                    (0.5562434876629774, 0.25890108087028885),
+                    (3.406610105794941e-17, 0.8534829937280171),
+                ),
+                (
+                    (1.150468525493579, 0.6047831353619284),
+                    (0.6360695200157975, 1.209984075309996),
+                    (5.3129735870634804e-18, 1.7767327136159135),
+                ),
+            ];
+            for &((a, b), (c, d), (e, f)) in cases.iter() {
+                let x = Complex::new(a, b);
+                let y = Complex::new(c, d);
+                assert!(close_ulps(x.mul_fma(y), Complex::new(e, f), 2));
+                // (b + ai)(d + ci) = (bd - ac) + (bc + ad)i
+                let z = Complex::new(b, a).mul_fma(Complex::new(d, c));
+                assert!(close_ulps(z, Complex::new(-e, f), 2));
+            }
+            for &c in all_consts.iter() {
+                assert!(close(c.mul_fma(_05_05i), c * _05_05i));
+            }
+            let big = Complex::new(f64::MAX, f64::MAX);
+            assert_eq!(big.mul_fma(_1_0i), big * _1_0i);
+        }
+
+        #[test]
+        fn test_mul_add_fma() {
+            // (a, b, c, a * b + c) where c cancels the rounded product, from exact arithmetic
+            let cases = [
+                (
+                    (-0.1904817859607255, 0.23908954432198382),
+                    (1.6968423360949174, -0.1373997196009067),
+                    (0.29036672232378036, -0.4318694049032679),
+                    (-3.781191628036441e-17, 7.931339647425785e-18),
+                ),
+                (
+                    (0.03136509224908446, 0.3495393153995878),
+                    (-1.2613586245804935, 0.04763455616722201),
+                    (0.05621277977119947, 0.4394003678608023),
+                    (3.849196534188522e-18, -2.795376627559568e-17),
+                ),
+                (
+                    (0.5195308808672077, 1.1719074900798105),
+                    (-1.6235061750831261, -0.786394949501898),
+                    (-0.07812053824792109, 2.311155507594984),
+                    (6.912378376849774e-18, -3.5090483374805976e-17),
+                ),
+            ];
+            for &((a, b), (c, d), (e, f), (g, h)) in cases.iter() {
+                let x = Complex::new(a, b);
+                let y = Complex::new(c, d);
+                let z = Complex::new(e, f);
+                assert!(close_ulps(x.mul_add_fma(y, z), Complex::new(g, h), 2));
+            }
+            for &c in all_consts.iter() {
+                assert!(close(c.mul_add_fma(_05_05i, _1_1i), c * _05_05i + _1_1i));
+            }
+            let big = Complex::new(f64::MAX, f64::MAX);
+            assert_eq!(big.mul_add_fma(_1_1i, _0_0i), big * _1_1i + _0_0i);
+        }
+
+        #[test]
+        fn test_fdiv_hard_cases() {
+            // The hard cases from Baudin & Smith, "A Robust Complex Division in Scilab".
+            let p = |e: i32| 2f64.powi(e / 2) * 2f64.powi(e - e / 2);
+            let cases = [
+                (1.0, 1.0, 1.0, p(1023), p(-1023), -p(-1023)),
+                (1.0, 1.0, p(-1023), p(-1023), p(1023), 0.0),
+                (p(1023), p(-1023), p(677), p(-677), p(346), -p(-1008)),
+                (p(1023), p(1023), 1.0, 1.0, p(1023), 0.0),
+                (p(1020), p(-844), p(656), p(-780), p(364), -p(-1072)),
+                (p(-71), p(1021), p(1001), p(-323), p(-1072), p(20)),
+                (
+                    p(-347),
+                    p(-54),
+                    p(-1037),
+                    p(-1058),
+                    3.8981256045591133e289,
+                    8.174961907852354e295,
+                ),
+                (p(-1074), p(-1074), p(-1073), p(-1074), 0.6, 0.2),
+                (
+                    p(1015),
+                    p(-989),
+                    p(1023),
+                    p(1023),
+                    0.001953125,
+                    -0.001953125,
+                ),
+                (
+                    p(-622),
+                    p(-1071),
+                    p(-343),
+                    p(-798),
+                    1.0295115178936058e-84,
+                    6.971459875150762e-220,
+                ),
+            ];
+            for &(a, b, c, d, e, f) in &cases {
+                let x = Complex::new(a, b);
+                let y = Complex::new(c, d);
+                assert!(close_ulps(x.fdiv(y), Complex::new(e, f), 2));
+            }
+        }
+
+        #[test]
+        fn test_fdiv_scaled() {
+            // Scaling the operands by powers of two is exact, so the quotient of
+            // scaled operands must match the scaled quotient of the originals,
+            // which the textbook formula computes well in a moderate range.
+            let values = [
+                Complex::new(1.0, 2.0),
+                Complex::new(-3.5, 0.125),
+                Complex::new(0.1, -0.7),
+                Complex::new(1e-3, 1e3),
+                Complex::new(-7.0, -7.0),
+            ];
+            let scale =
+                |z: Complex64, e: i32| z.scale(2f64.powi(e / 2)).scale(2f64.powi(e - e / 2));
+            for &x in &values {
+                for &y in &values {
+                    let q = x / y;
+                    for m in (-1060..=1010).step_by(53) {
+                        for n in (-1060..=1010).step_by(67) {
+                            let (xm, yn) = (scale(x, m), scale(y, n));
+                            let expected = scale(q, m - n);
+                            if !xm.is_normal() || !yn.is_normal() || !expected.is_normal() {
+                                continue;
+                            }
+                            let actual = xm.fdiv(yn);
+                            assert!(close_ulps(actual, expected, 8), "{} {}", m, n);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_fdiv_special() {
+            let inf = f64::INFINITY;
+            let x = Complex::new(1.0, -2.0);
+            assert_eq!(x.fdiv(_0_0i), Complex::new(inf, -inf));
+            assert_eq!(Complex::new(inf, 1.0).fdiv(x), Complex::new(inf, inf));
+            assert_eq!(x.fdiv(Complex::new(inf, 1.0)), Complex::new(0.0, -0.0));
+            assert!(_0_0i.fdiv(_0_0i).is_nan());
+            assert_eq!(_1_0i.finv(), _1_0i);
+            assert_eq!(_0_1i.finv(), Complex::new(0.0, -1.0));
+            let z = _0_0i.finv();
+            assert!(z.re == inf && z.im.is_nan());
+        }
+
         pub(crate) fn close(a: Complex64, b: Complex64) -> bool {
             close_to_tol(a, b, 1e-10)
         }
 
-        fn close_to_tol(a: Complex64, b: Complex64, tol: f64) -> bool {
+        pub(crate) fn close_to_tol(a: Complex64, b: Complex64, tol: f64) -> bool {
             // returns true if a and b are reasonably close
             let close = (a == b) || (a - b).norm() < tol;
             if !close {
@@ -1815,7 +3076,7 @@ pub(crate) mod test {
                 close = false;
             }
 
-            if close == false {
+            if !close {
                 println!("{:?} != {:?}", a, b);
             }
             close
@@ -1889,6 +3150,96 @@ pub(crate) mod test {
             }
         }
 
+        #[test]
+        fn test_ln_near_unit_circle() {
+            // (z, ln(z)) from mpmath
+            let cases = [
+                ((0.6, 0.8), (2.2204460492503132e-17, 0.9272952180016123)),
+                (
+                    (0.9999999990686774, 9.5367431640625e-07),
+                    (-9.308678276974261e-10, 9.536743172941394e-07),
+                ),
+                (
+                    (0.8, 0.6000000000000009),
+                    (5.551115123125784e-16, 0.643501108793285),
+                ),
+                ((-0.28, 0.96), (-2.6645352591003756e-17, 1.8545904360032246)),
+                ((1.0, 1e-10), (5.0000000000000005e-21, 1e-10)),
+                (
+                    (0.9999999999, -1e-07),
+                    (-9.99950082790361e-11, -1.0000000000999967e-07),
+                ),
+            ];
+            for &((x, y), (u, v)) in cases.iter() {
+                assert!(close_ulps(Complex::new(x, y).ln(), Complex::new(u, v), 4));
+            }
+        }
+
+        #[test]
+        fn test_ln_1p() {
+            // (z, ln(1 + z)) from mpmath
+            let cases = [
+                ((1e-10, 1e-10), (1e-10, 9.999999999e-11)),
+                (
+                    (-1e-08, 3e-05),
+                    (-9.550000041202501e-09, 3.0000000291000003e-05),
+                ),
+                ((-0.2, 0.6), (-2.2204460492503132e-17, 0.6435011087932844)),
+                ((1.5, -0.5), (0.9359010884507957, -0.19739555984988075)),
+                (
+                    (-0.9999999999990905, 1e-12),
+                    (-27.329634050950872, 0.8327602300413938),
+                ),
+                ((0.3, 0.4), (0.30759281954511675, 0.2984989315861793)),
+                (
+                    (-0.5, 0.8660254037844386),
+                    (-4.345318932600586e-17, 1.0471975511965976),
+                ),
+            ];
+            for &((x, y), (u, v)) in cases.iter() {
+                assert!(close_ulps(
+                    Complex::new(x, y).ln_1p(),
+                    Complex::new(u, v),
+                    4
+                ));
+            }
+            for &c in all_consts.iter() {
+                assert!(close(c.ln_1p(), (c + 1.0).ln()));
+            }
+            let z = Complex::new(-0.0, -0.0);
+            let w = z.ln_1p();
+            assert!(w.re.is_sign_negative() && w.im.is_sign_negative());
+            assert!(close_naninf(_neginf_1i.ln_1p(), (_neginf_1i + 1.0).ln()));
+        }
+
+        #[test]
+        fn test_exp_m1() {
+            // (z, exp(z) - 1) from mpmath
+            let cases = [
+                ((1e-10, 1e-10), (1e-10, 1.0000000001000001e-10)),
+                ((-1e-08, 3e-05), (-1.044999994546625e-08, 2.99999996955e-05)),
+                ((0.5, -2.0), (-1.6861101411498431, -1.4991780090003948)),
+                ((-3.0, 0.001), (-0.9502129565256682, 4.978706007001963e-05)),
+                (
+                    (2e-09, -0.0001),
+                    (-3.0000000038333336e-09, -0.00010000000003333334),
+                ),
+                ((0.1, 0.1), (0.09964966682940916, 0.11033298873020372)),
+            ];
+            for &((x, y), (u, v)) in cases.iter() {
+                assert!(close_ulps(
+                    Complex::new(x, y).exp_m1(),
+                    Complex::new(u, v),
+                    4
+                ));
+            }
+            for &c in all_consts.iter() {
+                assert!(close(c.exp_m1(), c.exp() - 1.0));
+            }
+            assert_eq!(_inf_0i.exp_m1(), _inf_0i);
+            assert!(close_naninf(_neginf_1i.exp_m1(), _neginf_1i.exp() - 1.0));
+        }
+
         #[test]
         fn test_powc() {
             let a = Complex::new(2.0, -3.0);
@@ -1903,10 +3254,12 @@ pub(crate) mod test {
             ));
             let z = Complex::new(0.0, 0.0);
             assert!(close(z.powc(b), z));
-            assert!(z.powc(Complex64::new(0., INFINITY)).is_nan());
-            assert!(z.powc(Complex64::new(10., INFINITY)).is_nan());
-            assert!(z.powc(Complex64::new(INFINITY, INFINITY)).is_nan());
-            assert!(close(z.powc(Complex64::new(INFINITY, 0.)), z));
+            assert!(z.powc(Complex64::new(0., f64::INFINITY)).is_nan());
+            assert!(z.powc(Complex64::new(10., f64::INFINITY)).is_nan());
+            assert!(z
+                .powc(Complex64::new(f64::INFINITY, f64::INFINITY))
+                .is_nan());
+            assert!(close(z.powc(Complex64::new(f64::INFINITY, 0.)), z));
             assert!(z.powc(Complex64::new(-1., 0.)).re.is_infinite());
             assert!(z.powc(Complex64::new(-1., 0.)).im.is_nan());
 
@@ -2253,9 +3606,14 @@ pub(crate) mod test {
             assert!(close(_0_0i.acosh(), _0_1i.scale(f64::consts::PI / 2.0)));
             assert!(close(_1_0i.acosh(), _0_0i));
             assert!(close(
-                _1_0i.scale(-1.0).acosh(),
+                Complex::new(-1.0, 0.0).acosh(),
                 _0_1i.scale(f64::consts::PI)
             ));
+            // `scale(-1.0)` also negates the zero imaginary part
+            assert!(close(
+                _1_0i.scale(-1.0).acosh(),
+                _0_1i.scale(-f64::consts::PI)
+            ));
             for &c in all_consts.iter() {
                 // acosh(conj(z)) = conj(acosh(z))
                 assert!(close(c.conj().acosh(), c.conj().acosh()));
@@ -2285,6 +3643,258 @@ pub(crate) mod test {
             }
         }
 
+        #[test]
+        #[allow(clippy::approx_constant)]
+        fn test_inverse_trig_accuracy() {
+            type Case = (fn(Complex64) -> Complex64, (f64, f64), (f64, f64));
+            // (f, z, f(z)) from mpmath. The `sqrt`- and `ln`-based formulas lose
+            // all accuracy or overflow in most of these cases.
+            let cases: [Case; 19] = [
+                (
+                    Complex::asinh,
+                    (1e-10, 1.0),
+                    (1.0000000000083334e-05, 1.5707863267948967),
+                ),
+                (
+                    Complex::asinh,
+                    (3e-09, 0.999999999999),
+                    (5.476312801768639e-05, 1.5707415454098919),
+                ),
+                (
+                    Complex::asinh,
+                    (1e-20, 1.0000000001),
+                    (1.4142136208675862e-05, 1.570796326794896),
+                ),
+                (
+                    Complex::asinh,
+                    (1.5e308, 1e308),
+                    (710.4786833208968, 0.5880026035475675),
+                ),
+                (
+                    Complex::asin,
+                    (1e-300, 1.0000000001),
+                    (7.071067811511922e-301, 0.8813735870902537),
+                ),
+                (
+                    Complex::asin,
+                    (0.999999, 1e-320),
+                    (1.5693821131146521, 7.07099e-318),
+                ),
+                (
+                    Complex::asin,
+                    (1e308, -1e308),
+                    (0.7853981633974483, -710.235929413006),
+                ),
+                (
+                    Complex::acos,
+                    (10.0, 1e-300),
+                    (1.0050378152592122e-301, -2.993222846126381),
+                ),
+                (
+                    Complex::acos,
+                    (2.0, 1e-300),
+                    (5.773502691896257e-301, -1.3169578969248168),
+                ),
+                (
+                    Complex::acos,
+                    (1.0000000001, 1e-20),
+                    (7.071067519157329e-16, -1.4142136208675862e-05),
+                ),
+                (
+                    Complex::acos,
+                    (0.999999999, 1e-25),
+                    (4.4721358921319356e-05, -2.236068009678968e-21),
+                ),
+                (Complex::acos, (1e-08, 1e-08), (1.5707963167948966, -1e-08)),
+                (
+                    Complex::acos,
+                    (-1e300, 1e300),
+                    (2.356194490192345, -691.8152486690536),
+                ),
+                (
+                    Complex::acosh,
+                    (1.000000000001, 1e-30),
+                    (1.4142764231805424e-06, 7.070753521795857e-25),
+                ),
+                (
+                    Complex::acosh,
+                    (-0.5, 1e-300),
+                    (1.1547005383792515e-300, 2.0943951023931957),
+                ),
+                (
+                    Complex::acosh,
+                    (1.7e308, 1.7e308),
+                    (710.7665576640682, 0.7853981633974483),
+                ),
+                // some of the worst cases, at 2 ulps
+                (
+                    Complex::asinh,
+                    (-1.000001, -1.0),
+                    (-1.0612756307693811, -0.6662390809150934),
+                ),
+                (
+                    Complex::asin,
+                    (-1.0, -1.000001),
+                    (-0.6662390809150934, -1.0612756307693811),
+                ),
+                (
+                    Complex::acosh,
+                    (-1e-10, -0.1),
+                    (0.09983407889920756, -1.5707963268944003),
+                ),
+            ];
+            for &(f, (x, y), (u, v)) in cases.iter() {
+                assert!(close_ulps(f(Complex::new(x, y)), Complex::new(u, v), 2));
+            }
+        }
+
+        #[test]
+        #[allow(clippy::approx_constant)]
+        fn test_extreme_magnitudes() {
+            type Case = (fn(Complex64) -> Complex64, (f64, f64), (f64, f64));
+            // (f, z, f(z)) from mpmath. Computing `norm_sqr` or `hypot` directly
+            // would overflow or underflow in these cases.
+            let max = f64::MAX;
+            let cases: [Case; 13] = [
+                (
+                    Complex::sqrt,
+                    (max, max),
+                    (1.4730945569055652e154, 6.1017574412827024e153),
+                ),
+                (
+                    Complex::sqrt,
+                    (-max, 1e-20),
+                    (3.729170365600103e-175, 1.3407807929942596e154),
+                ),
+                (
+                    Complex::sqrt,
+                    (3e-320, -5e-324),
+                    (1.7320411721116835e-160, -1.4262526024103912e-164),
+                ),
+                (
+                    Complex::sqrt,
+                    (-1e300, 1e300),
+                    (4.550898605622274e149, 1.09868411346781e150),
+                ),
+                (
+                    Complex::sqrt,
+                    (1e-300, 1e-310),
+                    (1e-150, 4.999999999999985e-161),
+                ),
+                (
+                    Complex::ln,
+                    (max, max),
+                    (710.1292864836639, 0.7853981633974483),
+                ),
+                (
+                    Complex::ln,
+                    (1e300, -1e300),
+                    (691.1221014884936, -0.7853981633974483),
+                ),
+                (
+                    Complex::ln,
+                    (5e-324, 5e-324),
+                    (-744.0934983311013, 0.7853981633974483),
+                ),
+                (
+                    Complex::ln,
+                    (-1e-310, 3e-320),
+                    (-713.8013788281542, 3.1415926532897966),
+                ),
+                (Complex::atanh, (1e300, 1e300), (5e-301, 1.5707963267948966)),
+                (
+                    Complex::atanh,
+                    (max, 0.5),
+                    (5.562684646268003e-309, 1.5707963267948966),
+                ),
+                (
+                    Complex::atanh,
+                    (1.0, 1e-300),
+                    (345.73433753938684, 0.7853981633974483),
+                ),
+                (
+                    Complex::atanh,
+                    (1.0, -1e-20),
+                    (23.37242452022043, -0.7853981633974483),
+                ),
+            ];
+            for &(f, (x, y), (u, v)) in cases.iter() {
+                assert!(close_ulps(f(Complex::new(x, y)), Complex::new(u, v), 2));
+            }
+        }
+
+        #[test]
+        fn test_norm_sqr_scaled() {
+            fn test(c: Complex64, m: f64, e: i32) {
+                assert_eq!(c.norm_sqr_scaled(), (m, e));
+            }
+            test(_0_0i, 0.0, 0);
+            test(_1_0i, 0.5, 1);
+            test(_1_1i, 0.5, 2);
+            test(Complex::new(3.0, 4.0), 25.0 / 32.0, 5);
+            test(
+                Complex::new(3.0, 4.0).scale(2f64.powi(1000)),
+                25.0 / 32.0,
+                2005,
+            );
+            let tiny = 2f64.powi(-535) * 2f64.powi(-535);
+            test(Complex::new(3.0, -4.0).scale(tiny), 25.0 / 32.0, -2135);
+            // MAX² = (2 - ε)² 2^2046 = (1 - ε + ε²/4) 2^2048
+            test(Complex::new(f64::MAX, 0.0), 1.0 - f64::EPSILON, 2048);
+
+            let (m, e) = _inf_0i.norm_sqr_scaled();
+            assert!(m.is_infinite() && e == 0);
+            let (m, e) = _nan_nani.norm_sqr_scaled();
+            assert!(m.is_nan() && e == 0);
+        }
+
+        #[test]
+        fn test_branch_cuts() {
+            // On a cut, the sign of a zero part selects the limit from that side.
+            type Func = fn(Complex64) -> Complex64;
+            let eps = 1e-12;
+            let real_cuts: [(Func, f64); 14] = [
+                (Complex64::ln, -2.0),
+                (Complex64::ln, -0.5),
+                (Complex64::log2, -3.0),
+                (Complex64::log10, -3.0),
+                (Complex64::sqrt, -4.0),
+                (Complex64::cbrt, -8.0),
+                (Complex64::asin, 2.0),
+                (Complex64::asin, -2.0),
+                (Complex64::acos, 2.0),
+                (Complex64::acos, -2.0),
+                (Complex64::acosh, 0.5),
+                (Complex64::acosh, -2.0),
+                (Complex64::atanh, 2.0),
+                (Complex64::atanh, -2.0),
+            ];
+            for &(f, x) in &real_cuts {
+                let above = f(Complex::new(x, 0.0));
+                let below = f(Complex::new(x, -0.0));
+                assert!(close_to_tol(above, f(Complex::new(x, eps)), 1e-6));
+                assert!(close_to_tol(below, f(Complex::new(x, -eps)), 1e-6));
+                assert!(!close_to_tol(above, below, 1e-6));
+            }
+
+            let imag_cuts: [(Func, f64); 4] = [
+                (Complex64::atan, 2.0),
+                (Complex64::atan, -2.0),
+                (Complex64::asinh, 2.0),
+                (Complex64::asinh, -2.0),
+            ];
+            for &(f, y) in &imag_cuts {
+                let right = f(Complex::new(0.0, y));
+                let left = f(Complex::new(-0.0, y));
+                assert!(close_to_tol(right, f(Complex::new(eps, y)), 1e-6));
+                assert!(close_to_tol(left, f(Complex::new(-eps, y)), 1e-6));
+                assert!(!close_to_tol(right, left, 1e-6));
+            }
+
+            assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+            assert_eq!(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+        }
+
         #[test]
         fn test_exp_ln() {
             for &c in all_consts.iter() {
@@ -2379,6 +3989,401 @@ pub(crate) mod test {
                 ));
             }
         }
+
+        /// Special values from the tables of C99 Annex G (G.6).
+        mod annex_g {
+            use super::super::Complex64;
+            use super::ulps;
+            use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            use std::vec;
+
+            const INF: f64 = f64::INFINITY;
+            const NAN: f64 = f64::NAN;
+
+            /// Which parts of an expected result have an unspecified sign.
+            #[derive(Clone, Copy, PartialEq)]
+            enum Sign {
+                Exact,
+                AnyRe,
+                AnyIm,
+                AnyBoth,
+            }
+            use self::Sign::*;
+
+            /// The symmetries of a function, used to extend each row of a
+            /// table to the other quadrants.
+            #[derive(Clone, Copy)]
+            enum Sym {
+                Conj,
+                ConjOdd,
+                ConjEven,
+            }
+            use self::Sym::*;
+
+            type Row = (f64, f64, f64, f64, Sign);
+
+            fn same(actual: f64, expected: f64, any_sign: bool) -> bool {
+                if actual.is_nan() || expected.is_nan() {
+                    actual.is_nan() && expected.is_nan()
+                } else if any_sign {
+                    ulps(actual.abs(), expected.abs()) <= 2
+                } else {
+                    // `ulps` also distinguishes the sign of zero
+                    actual.is_sign_negative() == expected.is_sign_negative()
+                        && ulps(actual, expected) <= 2
+                }
+            }
+
+            fn check(f: fn(Complex64) -> Complex64, sym: Sym, rows: &[Row]) {
+                for &(x, y, re, im, sign) in rows {
+                    let z = Complex64::new(x, y);
+                    let w = Complex64::new(re, im);
+                    let mut cases = vec![(z, w), (z.conj(), w.conj())];
+                    match sym {
+                        Conj => {}
+                        ConjOdd => {
+                            cases.push((-z, -w));
+                            cases.push((-z.conj(), -w.conj()));
+                        }
+                        ConjEven => {
+                            cases.push((-z, w));
+                            cases.push((-z.conj(), w.conj()));
+                        }
+                    }
+                    for (z, w) in cases {
+                        let r = f(z);
+                        let any_re = sign == AnyRe || sign == AnyBoth;
+                        let any_im = sign == AnyIm || sign == AnyBoth;
+                        assert!(
+                            same(r.re, w.re, any_re) && same(r.im, w.im, any_im),
+                            "f({:?}) = {:?}, expected {:?}",
+                            z,
+                            r,
+                            w
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn test_acosh() {
+                check(
+                    Complex64::acosh,
+                    Conj,
+                    &[
+                        (0.0, 0.0, 0.0, FRAC_PI_2, Exact),
+                        (-0.0, 0.0, 0.0, FRAC_PI_2, Exact),
+                        (0.0, INF, INF, FRAC_PI_2, Exact),
+                        (1.5, INF, INF, FRAC_PI_2, Exact),
+                        (-1.5, INF, INF, FRAC_PI_2, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (-1.5, NAN, NAN, NAN, Exact),
+                        (-INF, 0.0, INF, PI, Exact),
+                        (-INF, 1.5, INF, PI, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF, 0.0, Exact),
+                        (-INF, INF, INF, 3.0 * FRAC_PI_4, Exact),
+                        (INF, INF, INF, FRAC_PI_4, Exact),
+                        (-INF, NAN, INF, NAN, Exact),
+                        (INF, NAN, INF, NAN, Exact),
+                        (NAN, 0.0, NAN, NAN, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, INF, INF, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_asinh() {
+                check(
+                    Complex64::asinh,
+                    ConjOdd,
+                    &[
+                        (0.0, 0.0, 0.0, 0.0, Exact),
+                        (0.0, INF, INF, FRAC_PI_2, Exact),
+                        (1.5, INF, INF, FRAC_PI_2, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF, 0.0, Exact),
+                        (INF, INF, INF, FRAC_PI_4, Exact),
+                        (INF, NAN, INF, NAN, Exact),
+                        (NAN, 0.0, NAN, 0.0, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, INF, INF, NAN, AnyRe),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_atanh() {
+                check(
+                    Complex64::atanh,
+                    ConjOdd,
+                    &[
+                        (0.0, 0.0, 0.0, 0.0, Exact),
+                        (0.0, NAN, 0.0, NAN, Exact),
+                        (1.0, 0.0, INF, 0.0, Exact),
+                        (0.0, INF, 0.0, FRAC_PI_2, Exact),
+                        (1.5, INF, 0.0, FRAC_PI_2, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, 0.0, FRAC_PI_2, Exact),
+                        (INF, 1.5, 0.0, FRAC_PI_2, Exact),
+                        (INF, INF, 0.0, FRAC_PI_2, Exact),
+                        (INF, NAN, 0.0, NAN, Exact),
+                        (NAN, 0.0, NAN, NAN, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, INF, 0.0, FRAC_PI_2, AnyRe),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_cosh() {
+                check(
+                    Complex64::cosh,
+                    ConjEven,
+                    &[
+                        (0.0, 0.0, 1.0, 0.0, Exact),
+                        (0.0, INF, NAN, 0.0, AnyIm),
+                        (0.0, NAN, NAN, 0.0, AnyIm),
+                        (1.5, INF, NAN, NAN, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF * 1.5f64.cos(), INF * 1.5f64.sin(), Exact),
+                        (INF, 4.0, INF * 4.0f64.cos(), INF * 4.0f64.sin(), Exact),
+                        (INF, INF, INF, NAN, AnyRe),
+                        (INF, NAN, INF, NAN, Exact),
+                        (NAN, 0.0, NAN, 0.0, AnyIm),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_sinh() {
+                check(
+                    Complex64::sinh,
+                    ConjOdd,
+                    &[
+                        (0.0, 0.0, 0.0, 0.0, Exact),
+                        (0.0, INF, 0.0, NAN, AnyRe),
+                        (0.0, NAN, 0.0, NAN, AnyRe),
+                        (1.5, INF, NAN, NAN, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF * 1.5f64.cos(), INF * 1.5f64.sin(), Exact),
+                        (INF, 4.0, INF * 4.0f64.cos(), INF * 4.0f64.sin(), Exact),
+                        (INF, INF, INF, NAN, AnyRe),
+                        (INF, NAN, INF, NAN, AnyRe),
+                        (NAN, 0.0, NAN, 0.0, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_tanh() {
+                check(
+                    Complex64::tanh,
+                    ConjOdd,
+                    &[
+                        (0.0, 0.0, 0.0, 0.0, Exact),
+                        (0.0, INF, NAN, NAN, Exact),
+                        (1.5, INF, NAN, NAN, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, 1.0, 0.0, Exact),
+                        (INF, 1.0, 1.0, 0.0, Exact),
+                        (INF, 2.0, 1.0, -0.0, Exact),
+                        (INF, INF, 1.0, 0.0, AnyIm),
+                        (INF, NAN, 1.0, 0.0, AnyIm),
+                        (NAN, 0.0, NAN, 0.0, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_exp() {
+                check(
+                    Complex64::exp,
+                    Conj,
+                    &[
+                        (0.0, 0.0, 1.0, 0.0, Exact),
+                        (-0.0, 0.0, 1.0, 0.0, Exact),
+                        (0.0, INF, NAN, NAN, Exact),
+                        (-1.5, INF, NAN, NAN, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (-INF, 0.0, 0.0, 0.0, Exact),
+                        (-INF, 1.5, 0.0 * 1.5f64.cos(), 0.0 * 1.5f64.sin(), Exact),
+                        (-INF, 4.0, 0.0 * 4.0f64.cos(), 0.0 * 4.0f64.sin(), Exact),
+                        (INF, 1.5, INF * 1.5f64.cos(), INF * 1.5f64.sin(), Exact),
+                        (INF, 4.0, INF * 4.0f64.cos(), INF * 4.0f64.sin(), Exact),
+                        (-INF, INF, 0.0, 0.0, AnyBoth),
+                        (INF, INF, INF, NAN, AnyRe),
+                        (-INF, NAN, 0.0, 0.0, AnyBoth),
+                        (INF, NAN, INF, NAN, AnyRe),
+                        (NAN, 0.0, NAN, 0.0, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_ln() {
+                check(
+                    Complex64::ln,
+                    Conj,
+                    &[
+                        (-0.0, 0.0, -INF, PI, Exact),
+                        (0.0, 0.0, -INF, 0.0, Exact),
+                        (0.0, INF, INF, FRAC_PI_2, Exact),
+                        (-1.5, INF, INF, FRAC_PI_2, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (-1.5, NAN, NAN, NAN, Exact),
+                        (-INF, 0.0, INF, PI, Exact),
+                        (-INF, 1.5, INF, PI, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF, 0.0, Exact),
+                        (-INF, INF, INF, 3.0 * FRAC_PI_4, Exact),
+                        (INF, INF, INF, FRAC_PI_4, Exact),
+                        (-INF, NAN, INF, NAN, Exact),
+                        (INF, NAN, INF, NAN, Exact),
+                        (NAN, 0.0, NAN, NAN, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, INF, INF, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_sqrt() {
+                check(
+                    Complex64::sqrt,
+                    Conj,
+                    &[
+                        (0.0, 0.0, 0.0, 0.0, Exact),
+                        (-0.0, 0.0, 0.0, 0.0, Exact),
+                        (0.0, INF, INF, INF, Exact),
+                        (-1.5, INF, INF, INF, Exact),
+                        (INF, INF, INF, INF, Exact),
+                        (-INF, INF, INF, INF, Exact),
+                        (NAN, INF, INF, INF, Exact),
+                        (0.0, NAN, NAN, NAN, Exact),
+                        (-1.5, NAN, NAN, NAN, Exact),
+                        (-INF, 0.0, 0.0, INF, Exact),
+                        (-INF, 1.5, 0.0, INF, Exact),
+                        (INF, 0.0, INF, 0.0, Exact),
+                        (INF, 1.5, INF, 0.0, Exact),
+                        (-INF, NAN, NAN, INF, AnyIm),
+                        (INF, NAN, INF, NAN, Exact),
+                        (NAN, 0.0, NAN, NAN, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            #[test]
+            fn test_acos() {
+                check(
+                    Complex64::acos,
+                    Conj,
+                    &[
+                        (0.0, 0.0, FRAC_PI_2, -0.0, Exact),
+                        (-0.0, 0.0, FRAC_PI_2, -0.0, Exact),
+                        (0.0, NAN, FRAC_PI_2, NAN, Exact),
+                        (-0.0, NAN, FRAC_PI_2, NAN, Exact),
+                        (0.0, INF, FRAC_PI_2, -INF, Exact),
+                        (1.5, INF, FRAC_PI_2, -INF, Exact),
+                        (-1.5, INF, FRAC_PI_2, -INF, Exact),
+                        (1.5, NAN, NAN, NAN, Exact),
+                        (-1.5, NAN, NAN, NAN, Exact),
+                        (-INF, 0.0, PI, -INF, Exact),
+                        (-INF, 1.5, PI, -INF, Exact),
+                        (INF, 0.0, 0.0, -INF, Exact),
+                        (INF, 1.5, 0.0, -INF, Exact),
+                        (-INF, INF, 3.0 * FRAC_PI_4, -INF, Exact),
+                        (INF, INF, FRAC_PI_4, -INF, Exact),
+                        (-INF, NAN, NAN, INF, AnyIm),
+                        (INF, NAN, NAN, INF, AnyIm),
+                        (NAN, 0.0, NAN, NAN, Exact),
+                        (NAN, 1.5, NAN, NAN, Exact),
+                        (NAN, INF, NAN, -INF, Exact),
+                        (NAN, NAN, NAN, NAN, Exact),
+                    ],
+                );
+            }
+
+            /// All combinations of the interesting values, for the functions
+            /// that Annex G specifies in terms of others.
+            fn special_values() -> vec::Vec<Complex64> {
+                let parts = [0.0, -0.0, 1.5, -1.5, 4.0, INF, -INF, NAN];
+                let mut values = vec![];
+                for &x in &parts {
+                    for &y in &parts {
+                        values.push(Complex64::new(x, y));
+                    }
+                }
+                values
+            }
+
+            fn identical(a: Complex64, b: Complex64) -> bool {
+                same(a.re, b.re, false) && same(a.im, b.im, false)
+            }
+
+            #[test]
+            fn test_trig_identities() {
+                // i z and -i z, exactly
+                let mul_i = |z: Complex64| Complex64::new(-z.im, z.re);
+                let mul_neg_i = |z: Complex64| Complex64::new(z.im, -z.re);
+                for z in special_values() {
+                    // casin(z) = -i casinh(iz)
+                    assert!(identical(z.asin(), mul_neg_i(mul_i(z).asinh())));
+                    // catan(z) = -i catanh(iz)
+                    assert!(identical(z.atan(), mul_neg_i(mul_i(z).atanh())));
+                    // ccos(z) = ccosh(iz)
+                    assert!(identical(z.cos(), mul_i(z).cosh()));
+                    // csin(z) = -i csinh(iz)
+                    assert!(identical(z.sin(), mul_neg_i(mul_i(z).sinh())));
+                    // ctan(z) = -i ctanh(iz)
+                    assert!(identical(z.tan(), mul_neg_i(mul_i(z).tanh())));
+                }
+            }
+
+            #[test]
+            fn test_is_infinite() {
+                for z in special_values() {
+                    assert_eq!(z.is_infinite(), z.re.is_infinite() || z.im.is_infinite());
+                }
+                assert!(Complex64::new(INF, NAN).is_infinite());
+                assert!(Complex64::new(NAN, -INF).is_infinite());
+            }
+
+            #[test]
+            fn test_finite_overflow() {
+                // Results that overflow stay real, rather than turning into NaN.
+                let z = Complex64::new(1000.0, 0.0);
+                assert!(identical(z.exp(), Complex64::new(INF, 0.0)));
+                assert!(identical(z.sinh(), Complex64::new(INF, 0.0)));
+                assert!(identical(z.cosh(), Complex64::new(INF, 0.0)));
+                assert!(identical(z.tanh(), Complex64::new(1.0, 0.0)));
+                let z = Complex64::new(1000.0, 1.0);
+                assert_eq!(z.tanh().re, 1.0);
+                assert!(z.tanh().im == 0.0 && z.tanh().im.is_sign_positive());
+            }
+        }
     }
 
     // Test both a + b and a += b
@@ -2594,6 +4599,9 @@ pub(crate) mod test {
         fn test_sub() {
             test_op!(_4_2i - 0.5, Complex::new(3.5, 2.0));
             assert_eq!(0.5 - _4_2i, Complex::new(-3.5, -2.0));
+            // the sign of zero is kept
+            assert!((0.5 - Complex::<f64>::new(1.0, 0.0)).im.is_sign_negative());
+            assert!((0.5 - Complex::<f64>::new(1.0, -0.0)).im.is_sign_positive());
         }
 
         #[test]
@@ -2606,6 +4614,9 @@ pub(crate) mod test {
         fn test_div() {
             assert_eq!(_4_2i / 0.5, Complex::new(8.0, 4.0));
             assert_eq!(0.5 / _4_2i, Complex::new(0.1, -0.05));
+            // the sign of zero is kept
+            assert!((0.5 / Complex::<f64>::new(1.0, 0.0)).im.is_sign_negative());
+            assert!((0.5 / Complex::<f64>::new(1.0, -0.0)).im.is_sign_positive());
         }
 
         #[test]
@@ -0,0 +1,440 @@
+//! Checked, wrapping, saturating and overflowing arithmetic for complex
+//! integers, whose plain operators overflow like those of `T`.
+
+use num_traits::ops::overflowing::OverflowingMul;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Num, One, SaturatingMul,
+    WrappingAdd, WrappingMul, WrappingSub,
+};
+
+use crate::Complex;
+
+impl<T: Clone + Num + CheckedAdd + CheckedSub + CheckedMul> Complex<T> {
+    /// Returns `re² + im²`, or `None` if it overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// assert_eq!(Complex::new(3i16, -4).checked_norm_sqr(), Some(25));
+    /// assert_eq!(Complex::new(200i16, 100).checked_norm_sqr(), None);
+    /// ```
+    #[inline]
+    pub fn checked_norm_sqr(&self) -> Option<T> {
+        checked_dot(&self.re, &self.re, &self.im, &self.im)
+    }
+
+    /// Raises `self` to an unsigned integer power, or returns `None` if any
+    /// product along the way overflows, as for [`CheckedMul`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex;
+    ///
+    /// let z = Complex::new(3i32, 4);
+    /// assert_eq!(z.checked_pow(3), Some(Complex::new(-117, 44)));
+    /// assert_eq!(z.checked_pow(20), None);
+    /// ```
+    pub fn checked_pow(&self, mut exp: u32) -> Option<Self> {
+        let mut base = self.clone();
+        let mut acc = Self::one();
+        loop {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(&base)?;
+            }
+            exp >>= 1;
+            if exp == 0 {
+                return Some(acc);
+            }
+            base = base.checked_mul(&base)?;
+        }
+    }
+}
+
+impl<T: Clone + Num + CheckedAdd> CheckedAdd for Complex<T> {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        let re = self.re.checked_add(&v.re)?;
+        let im = self.im.checked_add(&v.im)?;
+        Some(Self::new(re, im))
+    }
+}
+
+impl<T: Clone + Num + CheckedSub> CheckedSub for Complex<T> {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let re = self.re.checked_sub(&v.re)?;
+        let im = self.im.checked_sub(&v.im)?;
+        Some(Self::new(re, im))
+    }
+}
+
+/// Multiplies as `Mul` does, returning `None` if any of the products `ac`,
+/// `bd`, `ad`, `bc` or their sum or difference overflows, even when the
+/// result itself would fit.
+impl<T: Clone + Num + CheckedAdd + CheckedSub + CheckedMul> CheckedMul for Complex<T> {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        let re = checked_cross(&self.re, &v.re, &self.im, &v.im)?;
+        let im = checked_dot(&self.re, &v.im, &self.im, &v.re)?;
+        Some(Self::new(re, im))
+    }
+}
+
+/// Divides as `Div` does, returning `None` if the divisor is zero, or if its
+/// `norm_sqr` or any intermediate product overflows.
+impl<T> CheckedDiv for Complex<T>
+where
+    T: Clone + Num + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv,
+{
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        let norm_sqr = v.checked_norm_sqr()?;
+        let re = checked_dot(&self.re, &v.re, &self.im, &v.im)?;
+        let im = checked_cross(&self.im, &v.re, &self.re, &v.im)?;
+        Some(Self::new(
+            re.checked_div(&norm_sqr)?,
+            im.checked_div(&norm_sqr)?,
+        ))
+    }
+}
+
+impl<T: Clone + Num + CheckedNeg> CheckedNeg for Complex<T> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        let re = self.re.checked_neg()?;
+        let im = self.im.checked_neg()?;
+        Some(Self::new(re, im))
+    }
+}
+
+impl<T: Clone + Num + WrappingAdd> WrappingAdd for Complex<T> {
+    #[inline]
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Self::new(self.re.wrapping_add(&v.re), self.im.wrapping_add(&v.im))
+    }
+}
+
+impl<T: Clone + Num + WrappingSub> WrappingSub for Complex<T> {
+    #[inline]
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Self::new(self.re.wrapping_sub(&v.re), self.im.wrapping_sub(&v.im))
+    }
+}
+
+/// Multiplies modulo the range of `T` in each part, which is the exact
+/// product wrapped around, since wrapping arithmetic is exact modulo `2ⁿ`.
+impl<T: Clone + Num + WrappingAdd + WrappingSub + WrappingMul> WrappingMul for Complex<T> {
+    #[inline]
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        let (a, b, c, d) = (&self.re, &self.im, &v.re, &v.im);
+        let re = a.wrapping_mul(c).wrapping_sub(&b.wrapping_mul(d));
+        let im = a.wrapping_mul(d).wrapping_add(&b.wrapping_mul(c));
+        Self::new(re, im)
+    }
+}
+
+/// Returns `a b + c d`, or `None` on overflow.
+#[inline]
+fn checked_dot<T: CheckedAdd + CheckedMul>(a: &T, b: &T, c: &T, d: &T) -> Option<T> {
+    a.checked_mul(b)?.checked_add(&c.checked_mul(d)?)
+}
+
+/// Returns `a b - c d`, or `None` on overflow.
+#[inline]
+fn checked_cross<T: CheckedSub + CheckedMul>(a: &T, b: &T, c: &T, d: &T) -> Option<T> {
+    a.checked_mul(b)?.checked_sub(&c.checked_mul(d)?)
+}
+
+// `SaturatingMul` and `OverflowingMul` find the exact product in the integer
+// type of twice the width, where `ac`, `bd`, `ad` and `bc` are exact, and
+// only their sum or difference can overflow, to a known side.  There is no
+// such type for `i128` and `u128`.
+macro_rules! wide_mul {
+    ($($t:ident => $wide:ident),*) => {$(
+        impl SaturatingMul for Complex<$t> {
+            /// Multiplies as `Mul` does, with each part of the exact product
+            /// clamped to the range of the type.
+            #[inline]
+            fn saturating_mul(&self, v: &Self) -> Self {
+                let clamp = |x: $wide| {
+                    <$t>::try_from(x).unwrap_or(if x > 0 { <$t>::MAX } else { <$t>::MIN })
+                };
+                let [ac, bd, ad, bc] = wide_products!(self, v, $wide);
+                let re = ac.checked_sub(bd).map_or(if ac > bd { <$t>::MAX } else { <$t>::MIN }, clamp);
+                let im = ad.checked_add(bc).map_or(if ad > 0 { <$t>::MAX } else { <$t>::MIN }, clamp);
+                Complex::new(re, im)
+            }
+        }
+
+        impl OverflowingMul for Complex<$t> {
+            /// Returns the wrapping product, as `WrappingMul`, and whether
+            /// either part of the exact product overflowed.
+            #[inline]
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                let [ac, bd, ad, bc] = wide_products!(self, v, $wide);
+                let (re, re_carry) = ac.overflowing_sub(bd);
+                let (im, im_carry) = ad.overflowing_add(bc);
+                let fits = |x: $wide| <$t>::try_from(x).is_ok();
+                let overflow = re_carry || im_carry || !fits(re) || !fits(im);
+                (Complex::new(re as $t, im as $t), overflow)
+            }
+        }
+    )*};
+}
+
+macro_rules! wide_products {
+    ($x:expr, $y:expr, $wide:ident) => {{
+        let (a, b) = ($wide::from($x.re), $wide::from($x.im));
+        let (c, d) = ($wide::from($y.re), $wide::from($y.im));
+        [a * c, b * d, a * d, b * c]
+    }};
+}
+
+wide_mul!(i8 => i16, i16 => i32, i32 => i64, i64 => i128);
+wide_mul!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+
+// `isize` and `usize` delegate to the fixed-width type of the same size
+macro_rules! wide_mul_size {
+    ($($t:ident as $fixed:ident),*) => {$(
+        impl SaturatingMul for Complex<$t> {
+            /// Multiplies as `Mul` does, with each part of the exact product
+            /// clamped to the range of the type.
+            #[inline]
+            fn saturating_mul(&self, v: &Self) -> Self {
+                let (x, y) = (fixed!(self, $fixed), fixed!(v, $fixed));
+                let z = x.saturating_mul(&y);
+                Complex::new(z.re as $t, z.im as $t)
+            }
+        }
+
+        impl OverflowingMul for Complex<$t> {
+            /// Returns the wrapping product, as `WrappingMul`, and whether
+            /// either part of the exact product overflowed.
+            #[inline]
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                let (x, y) = (fixed!(self, $fixed), fixed!(v, $fixed));
+                let (z, overflow) = x.overflowing_mul(&y);
+                (Complex::new(z.re as $t, z.im as $t), overflow)
+            }
+        }
+    )*};
+}
+
+macro_rules! fixed {
+    ($x:expr, $fixed:ident) => {
+        Complex::new($x.re as $fixed, $x.im as $fixed)
+    };
+}
+
+#[cfg(target_pointer_width = "16")]
+wide_mul_size!(isize as i16, usize as u16);
+#[cfg(target_pointer_width = "32")]
+wide_mul_size!(isize as i32, usize as u32);
+#[cfg(target_pointer_width = "64")]
+wide_mul_size!(isize as i64, usize as u64);
+
+macro_rules! norm_sqr_wide {
+    ($($t:ident => $wide:ident),*) => {$(
+        impl Complex<$t> {
+            /// Returns `re² + im²` in the unsigned type of twice the width,
+            /// which holds it exactly for all values.
+            ///
+            /// Unsigned components have no such method, as their squares can
+            /// sum past twice their width, as `2 × 255²` does for `u8`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use num_complex::Complex;
+            ///
+            #[doc = concat!("let z = Complex::new(", stringify!($t), "::MIN, ", stringify!($t), "::MIN);")]
+            #[doc = concat!("assert_eq!(z.norm_sqr_wide(), 2 * ", stringify!($wide), "::from(", stringify!($t), "::MIN.unsigned_abs()).pow(2));")]
+            /// ```
+            #[inline]
+            pub fn norm_sqr_wide(&self) -> $wide {
+                let re = $wide::from(self.re.unsigned_abs());
+                let im = $wide::from(self.im.unsigned_abs());
+                re * re + im * im
+            }
+        }
+    )*};
+}
+
+// At most `2 × (2^(n-1))² = 2^(2n-1)` for `n` bits, so this fits in `2n`.
+norm_sqr_wide!(i8 => u16, i16 => u32, i32 => u64, i64 => u128);
+
+#[cfg(test)]
+mod test {
+    use crate::Complex;
+    use num_traits::ops::overflowing::OverflowingMul;
+    use num_traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, SaturatingMul, WrappingAdd,
+        WrappingMul, WrappingSub,
+    };
+
+    const VALUES: [i8; 13] = [-128, -127, -100, -64, -12, -1, 0, 1, 2, 11, 64, 100, 127];
+
+    fn complexes() -> impl Iterator<Item = Complex<i8>> {
+        VALUES
+            .iter()
+            .flat_map(|&a| VALUES.iter().map(move |&b| Complex::new(a, b)))
+    }
+
+    fn exact(z: Complex<i8>) -> Complex<i32> {
+        Complex::new(i32::from(z.re), i32::from(z.im))
+    }
+
+    fn narrow(z: Complex<i32>) -> Option<Complex<i8>> {
+        Some(Complex::new(
+            i8::try_from(z.re).ok()?,
+            i8::try_from(z.im).ok()?,
+        ))
+    }
+
+    #[test]
+    fn test_i8() {
+        for x in complexes() {
+            assert_eq!(x.checked_neg(), narrow(-exact(x)));
+            let n = x.norm_sqr_wide();
+            assert_eq!(i32::from(n), exact(x).norm_sqr());
+            assert_eq!(x.checked_norm_sqr(), i8::try_from(n).ok());
+            for y in complexes() {
+                let (ex, ey) = (exact(x), exact(y));
+                assert_eq!(x.checked_add(&y), narrow(ex + ey));
+                assert_eq!(x.checked_sub(&y), narrow(ex - ey));
+                let wrap = |z: Complex<i32>| Complex::new(z.re as i8, z.im as i8);
+                assert_eq!(x.wrapping_add(&y), wrap(ex + ey));
+                assert_eq!(x.wrapping_sub(&y), wrap(ex - ey));
+
+                let product = ex * ey;
+                assert_eq!(x.wrapping_mul(&y), wrap(product));
+                let (z, overflow) = x.overflowing_mul(&y);
+                assert_eq!(z, wrap(product));
+                assert_eq!(overflow, narrow(product).is_none());
+                let clamp = |t: i32| t.clamp(-128, 127) as i8;
+                let saturated = Complex::new(clamp(product.re), clamp(product.im));
+                assert_eq!(x.saturating_mul(&y), saturated);
+                if let Some(z) = x.checked_mul(&y) {
+                    assert_eq!(z, saturated);
+                }
+                let fits = |t: i32| i8::try_from(t).is_ok();
+                let (a, b, c, d) = (ex.re, ex.im, ey.re, ey.im);
+                if [a * c, b * d, a * d, b * c].iter().all(|&t| fits(t)) && !overflow {
+                    assert_eq!(x.checked_mul(&y), Some(z));
+                }
+
+                match x.checked_div(&y) {
+                    Some(z) => assert_eq!(exact(z), ex / ey),
+                    None => {
+                        let n = ey.norm_sqr();
+                        let dot = a * c + b * d;
+                        let cross = b * c - a * d;
+                        let products = [a * c, b * d, b * c, a * d, c * c, d * d];
+                        assert!(
+                            n == 0 || ![n, dot, cross].iter().chain(&products).all(|&t| fits(t))
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide() {
+        let (min, max) = (
+            Complex::new(i64::MIN, i64::MIN),
+            Complex::new(i64::MAX, i64::MAX),
+        );
+        assert_eq!(min.saturating_mul(&min), Complex::new(0, i64::MAX));
+        assert_eq!(min.saturating_mul(&max), Complex::new(0, i64::MIN));
+        assert_eq!(min.overflowing_mul(&min), (Complex::new(0, 0), true));
+        assert_eq!(min.norm_sqr_wide(), 1 << 127);
+        assert_eq!(
+            max.norm_sqr_wide(),
+            2 * u128::from(i64::MAX.unsigned_abs()).pow(2)
+        );
+
+        let u = Complex::new(u64::MAX, u64::MAX);
+        assert_eq!(u.saturating_mul(&u), Complex::new(0, u64::MAX));
+        assert_eq!(u.overflowing_mul(&u), (u.wrapping_mul(&u), true));
+        let u = Complex::new(3u32, 1);
+        assert_eq!(u.saturating_mul(&Complex::new(1, 4)), Complex::new(0, 13));
+        assert_eq!(
+            u.overflowing_mul(&Complex::new(2, 0)),
+            (Complex::new(6, 2), false)
+        );
+
+        let s = Complex::new(isize::MAX, 2);
+        assert_eq!(s.saturating_mul(&s), Complex::new(isize::MAX, isize::MAX));
+        assert_eq!(s.overflowing_mul(&s), (s.wrapping_mul(&s), true));
+        let s = Complex::new(usize::MAX, 0);
+        assert_eq!(
+            s.overflowing_mul(&Complex::new(1, 1)),
+            (Complex::new(usize::MAX, usize::MAX), false)
+        );
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        let max = u128::from(i64::MAX.unsigned_abs());
+        for &(re, im) in &[
+            (-3i64, 2),
+            (1, 1),
+            (0, -7),
+            (i64::MAX, 0),
+            (i64::MIN, i64::MIN),
+            (1 << 31, 1 << 31),
+            // the square fits, but not the products of its components
+            (3_254_976_541, 1_171_110_689),
+        ] {
+            let z = Complex::new(re, im);
+            let norm = u128::from(re.unsigned_abs()).pow(2) + u128::from(im.unsigned_abs()).pow(2);
+            for n in 0..70 {
+                // |z^n|² = |z|^(2n), and beyond 2^127 a component leaves i64
+                let norm = match norm.checked_pow(n) {
+                    Some(norm) if norm <= 1 << 127 => norm,
+                    _ => {
+                        assert_eq!(z.checked_pow(n), None, "{}^{}", z, n);
+                        continue;
+                    }
+                };
+                let exact = Complex::new(i128::from(re), i128::from(im)).powu(n);
+                let fits = |t: i128| i64::try_from(t).ok();
+                let expected =
+                    fits(exact.re).and_then(|re| Some(Complex::new(re, fits(exact.im)?)));
+                let result = z.checked_pow(n);
+                if norm <= max * max {
+                    // every product of components along the way is at most
+                    // |z^n| in magnitude, as is every partial power
+                    assert!(expected.is_some());
+                    assert_eq!(result, expected, "{}^{}", z, n);
+                } else {
+                    // otherwise it may fail early, but is never wrong
+                    assert!(result.is_none() || result == expected, "{}^{}", z, n);
+                }
+            }
+        }
+        let z = Complex::new(3_254_976_541i64, 1_171_110_689);
+        assert_eq!(z.checked_pow(2), None);
+        assert_eq!(
+            Complex::new(i128::from(z.re), i128::from(z.im)).powu(2),
+            Complex::new(9_223_372_036_570_269_960, 7_623_875_639_218_693_498)
+        );
+        assert_eq!(
+            Complex::new(0i8, 0).checked_pow(0),
+            Some(Complex::new(1, 0))
+        );
+        assert_eq!(
+            Complex::new(1i8, 1).checked_pow(13),
+            Some(Complex::new(-64, -64))
+        );
+        assert_eq!(
+            Complex::new(1i8, 1).checked_pow(14),
+            Some(Complex::new(0, -128))
+        );
+        assert_eq!(Complex::new(1i8, 1).checked_pow(15), None);
+    }
+}
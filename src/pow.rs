@@ -146,7 +146,7 @@ powf_impl!(f64);
 // foreign to anyone else trying to implement something that would overlap, raising E0117.
 
 #[cfg(any(feature = "std", feature = "libm"))]
-impl<'a, T: Float> Pow<Complex<T>> for &'a Complex<T> {
+impl<T: Float> Pow<Complex<T>> for &Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
@@ -156,11 +156,11 @@ impl<'a, T: Float> Pow<Complex<T>> for &'a Complex<T> {
 }
 
 #[cfg(any(feature = "std", feature = "libm"))]
-impl<'a, 'b, T: Float> Pow<&'b Complex<T>> for &'a Complex<T> {
+impl<T: Float> Pow<&Complex<T>> for &Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
-    fn pow(self, &exp: &'b Complex<T>) -> Self::Output {
+    fn pow(self, &exp: &Complex<T>) -> Self::Output {
         self.powc(exp)
     }
 }
@@ -176,11 +176,11 @@ impl<T: Float> Pow<Complex<T>> for Complex<T> {
 }
 
 #[cfg(any(feature = "std", feature = "libm"))]
-impl<'b, T: Float> Pow<&'b Complex<T>> for Complex<T> {
+impl<T: Float> Pow<&Complex<T>> for Complex<T> {
     type Output = Complex<T>;
 
     #[inline]
-    fn pow(self, &exp: &'b Complex<T>) -> Self::Output {
+    fn pow(self, &exp: &Complex<T>) -> Self::Output {
         self.powc(exp)
     }
 }
@@ -0,0 +1,583 @@
+//! The Riemann and Hurwitz zeta functions.
+//!
+//! Both are computed with the Euler–Maclaurin formula in the right
+//! half-plane, as in F. Johansson, "Rigorous high-precision computation of
+//! the Hurwitz zeta function and its derivatives", Numer. Algorithms 69(2),
+//! 2015.  In the left half-plane, `ζ(s)` uses Riemann's functional equation,
+//! and `ζ(s, a)` uses Hurwitz's formula, DLMF 25.11.9, with the periodic zeta
+//! function summed as the series of DLMF 25.12.12.
+
+use num_traits::{Float, FloatConst, One, Zero};
+
+use crate::gamma::ln_sin_pi;
+use crate::{constant, Complex};
+
+/// `B_2k / (2k)!`, for `k` from 1 up to 30.
+const BERNOULLI: [f64; 30] = [
+    0.08333333333333333,
+    -0.001388888888888889,
+    3.306878306878307e-05,
+    -8.267195767195768e-07,
+    2.08767569878681e-08,
+    -5.284190138687493e-10,
+    1.3382536530684679e-11,
+    -3.3896802963225827e-13,
+    8.586062056277845e-15,
+    -2.174868698558062e-16,
+    5.5090028283602295e-18,
+    -1.3954464685812522e-19,
+    3.534707039629467e-21,
+    -8.953517427037546e-23,
+    2.267952452337683e-24,
+    -5.744790668872202e-26,
+    1.455172475614865e-27,
+    -3.6859949406653103e-29,
+    9.336734257095045e-31,
+    -2.36502241570063e-32,
+    5.990671762482134e-34,
+    -1.5174548844682903e-35,
+    3.843758125454189e-37,
+    -9.736353072646691e-39,
+    2.466247044200681e-40,
+    -6.247076741820743e-42,
+    1.5824030244644914e-43,
+    -4.008273685948936e-45,
+    1.0153075855569557e-46,
+    -2.5718041582418717e-48,
+];
+
+/// The periodic zeta series has a removable singularity where `s` is a
+/// negative integer.  Within this distance of one, that term is averaged over
+/// a circle of radius `CAUCHY_RADIUS` with Cauchy's integral formula, using
+/// `CAUCHY_POINTS` points of the trapezoidal rule.
+const CAUCHY_NEAR: f64 = 0.25;
+const CAUCHY_RADIUS: f64 = 0.5;
+const CAUCHY_POINTS: u32 = 56;
+
+/// The periodic zeta series converges at least like `2^-k`, so this is far
+/// more than it ever needs.
+const PERIODIC_TERMS: u32 = 200;
+
+/// The periodic zeta function is summed directly, without cancellation, when
+/// `n^-Re(t)` falls below the precision within this many terms.
+const PERIODIC_DIRECT_MAX: f64 = 1000.0;
+
+/// The most terms `(n + a)^-s` with negative `n + a` that are summed one by
+/// one, so `ζ(s, a)` gives up for `a` below minus this.
+const NEGATIVE_TERMS: f64 = 1e6;
+
+impl<T: Float + FloatConst> Complex<T> {
+    /// Computes the Riemann zeta function, `ζ(self)`.
+    ///
+    /// This is `Σ n^-s` for `Re(s) > 1`, continued analytically to the rest of
+    /// the plane.  For `Re(s) < 0`, it uses the reflection formula
+    /// `ζ(s) = 2 (2π)^(s-1) sin(πs/2) Γ(1 - s) ζ(1 - s)`, so the trivial zeros
+    /// at the negative even integers are exact.
+    ///
+    /// The `f64` result is within about `1e-14` relative error for small
+    /// `|Im(s)|`, growing in proportion to it as the phases `Im(s) ln n` of the
+    /// terms are rounded; near the nontrivial zeros the error is absolute
+    /// instead.  The cost grows linearly with `|s|`.  At the pole `s = 1`,
+    /// this returns `+∞` with a NaN imaginary part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Complex64::new(2.0, 0.0).zeta();
+    /// assert!((z.re - PI * PI / 6.0).abs() < 1e-15);
+    /// assert_eq!(z.im, 0.0);
+    ///
+    /// assert_eq!(Complex64::new(-4.0, 0.0).zeta().re, 0.0);
+    ///
+    /// // near the first zero on the critical line
+    /// let z = Complex64::new(0.5, 14.134725141734693).zeta();
+    /// assert!(z.norm() < 1e-14);
+    /// ```
+    pub fn zeta(self) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite()) {
+            return if re == T::infinity() && im.is_finite() {
+                Self::one()
+            } else {
+                Self::new(T::nan(), T::nan())
+            };
+        }
+        if self.is_one() {
+            return Self::new(T::infinity(), T::nan());
+        }
+        let w = if re < T::zero() {
+            // ζ(1 - t) = 2 Γ(t) (2π)^-t cos(πt/2) ζ(t)
+            let two = T::one() + T::one();
+            let t = Self::one() - self;
+            let factor = if im.abs() <= two / T::PI() {
+                ln_reflection(t).exp() * t.unscale(two).cos_pi().scale(two)
+            } else {
+                let (plus, minus) = reflection(t);
+                plus + minus
+            };
+            factor * euler_maclaurin(t, T::one())
+        } else {
+            euler_maclaurin(self, T::one())
+        };
+        if im.is_zero() {
+            Self::new(w.re, im)
+        } else {
+            w
+        }
+    }
+
+    /// Computes the Hurwitz zeta function, `ζ(self, a) = Σ (n + a)^-s` for
+    /// `n ≥ 0`, continued analytically in `s`.
+    ///
+    /// For negative `a`, the powers of the negative terms are taken on the
+    /// principal branch.  `ζ(s, 1)` is the Riemann zeta function.
+    ///
+    /// For `Re(s) < 0`, this uses Hurwitz's formula with `a` reduced to
+    /// `(0, 1]`, unless the Euler–Maclaurin sum loses less to cancellation.
+    /// The `f64` result is usually within about `1e-13` relative error, but a
+    /// few more digits can be lost far into the left half-plane, when `Im(s)`
+    /// or `a` is large.  At the pole `s = 1`, and at non-positive integers `a`
+    /// with `Re(s) > 0`, this returns `+∞` with a NaN imaginary part.  The
+    /// terms with negative `n + a` are summed one by one, so for `a` below
+    /// `-10⁶` this returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    ///
+    /// // ζ(s, 1/2) = (2^s - 1) ζ(s)
+    /// let s = Complex64::new(3.0, 2.0);
+    /// let expected = (s.expf(2.0) - 1.0) * s.zeta();
+    /// assert!((s.hurwitz_zeta(0.5) - expected).norm() < 1e-14);
+    ///
+    /// // ζ(-1, a) = -B₂(a)/2 = -(a² - a + 1/6)/2
+    /// let z = Complex64::new(-1.0, 0.0).hurwitz_zeta(0.25);
+    /// assert!((z.re - 1.0 / 96.0).abs() < 1e-15);
+    /// assert_eq!(z.im, 0.0);
+    /// ```
+    pub fn hurwitz_zeta(self, a: T) -> Self {
+        let Complex { re, im } = self;
+        if !(re.is_finite() && im.is_finite() && a.is_finite()) {
+            return Self::new(T::nan(), T::nan());
+        }
+        if a.is_one() {
+            return self.zeta();
+        }
+        if self.is_one() || (re >= T::zero() && a <= T::zero() && a == a.floor()) {
+            // a pole, or the term n + a = 0
+            return Self::new(T::infinity(), T::nan());
+        }
+        if a < -constant::<T>(NEGATIVE_TERMS) {
+            return Self::new(T::nan(), T::nan());
+        }
+        let one = T::one();
+        let half = one / (one + one);
+        let large = euler_maclaurin_start(self);
+        // ζ(s, a) = ζ(s, f) ∓ Σ (x + j)^-s, with f in (0, 1]
+        let f = a - (a.ceil() - one);
+        let theta = if f <= half { f } else { f - one };
+        let direct = re >= T::zero() || a >= large || {
+            // The terms of the direct sum are up to large^-Re(s), and those
+            // of the periodic zeta series up to e^|θ Im(s)|, times the result.
+            let t = Self::one() - self;
+            let size = ln_reflection(t).re + T::PI() * im.abs() / (one + one);
+            let periodic = if periodic_direct_terms(t.re).is_some() {
+                T::zero()
+            } else {
+                (theta * im).abs()
+            };
+            -re * large.ln() - size <= periodic
+        };
+        let w = if direct {
+            euler_maclaurin(self, a)
+        } else {
+            let mut w = if f.is_one() {
+                self.zeta()
+            } else {
+                hurwitz_reflection(self, theta)
+            };
+            let mut x = f;
+            while x < a {
+                w = w - pow_neg(x, self);
+                x = x + one;
+            }
+            let mut x = a;
+            while x < f {
+                w = w + pow_neg(x, self);
+                x = x + one;
+            }
+            w
+        };
+        if im.is_zero() && (a > T::zero() || re == re.floor()) {
+            Self::new(w.re, im)
+        } else {
+            w
+        }
+    }
+}
+
+/// Returns `x^-s` for real `x`, on the principal branch for negative `x`.
+fn pow_neg<T: Float + FloatConst>(x: T, s: Complex<T>) -> Complex<T> {
+    if x.is_zero() {
+        return if s.re < T::zero() {
+            Complex::zero()
+        } else {
+            Complex::new(T::infinity(), T::nan())
+        };
+    }
+    let w = (-s).scale(x.abs().ln()).exp();
+    if x.is_sign_negative() {
+        // ln x = ln|x| + iπ
+        w * (-s).exp_i_pi()
+    } else {
+        w
+    }
+}
+
+/// Returns where the Euler–Maclaurin formula stops the direct sum, so that
+/// every term of its tail series is at most a quarter of the one before.
+fn euler_maclaurin_start<T: Float + FloatConst>(s: Complex<T>) -> T {
+    (s.norm() + constant(2.0 * BERNOULLI.len() as f64)) / T::PI()
+}
+
+/// The Euler–Maclaurin formula for `ζ(s, a)`: with `x = a + N`,
+///
+/// `Σ (n + a)^-s + x^(1-s)/(s-1) + x^-s/2 + Σ B_2k/(2k)! (s)_(2k-1) x^(-s-2k+1)`,
+///
+/// where `(s)_m` is the rising factorial and the first sum is for `n < N`.
+fn euler_maclaurin<T: Float + FloatConst>(s: Complex<T>, a: T) -> Complex<T> {
+    let one = T::one();
+    let two = one + one;
+    let large = euler_maclaurin_start(s);
+    let mut sum = Complex::zero();
+    let mut x = a;
+    while x < large {
+        sum = sum + pow_neg(x, s);
+        x = x + one;
+    }
+    let power = pow_neg(x, s);
+    sum = sum + power.scale(x) / (s - one) + power / two;
+    let r = x.recip();
+    let mut term = power * s.scale(r);
+    for (k, &b) in BERNOULLI.iter().enumerate() {
+        sum = sum + term.scale(constant(b));
+        let m = constant::<T>(2.0 * k as f64 + 1.0);
+        term = term * (s + m) * (s + m + one).scale(r * r);
+    }
+    sum
+}
+
+/// Returns `ln(Γ(t) (2π)^-t)`.
+fn ln_reflection<T: Float + FloatConst>(t: Complex<T>) -> Complex<T> {
+    t.ln_gamma() - t.scale((T::PI() + T::PI()).ln())
+}
+
+/// Returns `Γ(t) (2π)^-t e^(iπt/2)` and `Γ(t) (2π)^-t e^(-iπt/2)`, the
+/// factors of the functional equations, without overflow.
+fn reflection<T: Float + FloatConst>(t: Complex<T>) -> (Complex<T>, Complex<T>) {
+    let two = T::one() + T::one();
+    let g = ln_reflection(t);
+    let shift = T::PI() * t.im / two;
+    if shift.abs() <= T::one() {
+        // Share the rounding error of e^g, as the two may cancel.
+        let g = g.exp();
+        return (
+            g * t.unscale(two).exp_i_pi(),
+            g * (-t).unscale(two).exp_i_pi(),
+        );
+    }
+    let plus = Complex::new(g.re - shift, g.im).exp() * Complex::cis_pi(t.re / two);
+    let minus = Complex::new(g.re + shift, g.im).exp() * Complex::cis_pi(-t.re / two);
+    (plus, minus)
+}
+
+/// Hurwitz's formula for `ζ(s, a)` with `Re(s) < 0` and `0 < a < 1`:
+///
+/// `ζ(1 - t, a) = Γ(t) (2π)^-t (e^(iπt/2) F(-a, t) + e^(-iπt/2) F(a, t))`,
+///
+/// where `F(a, t) = Σ e^(2πina) n^-t` is the periodic zeta function, and
+/// `θ = a` or `a - 1`, whichever is smaller.
+fn hurwitz_reflection<T: Float + FloatConst>(s: Complex<T>, theta: T) -> Complex<T> {
+    let t = Complex::<T>::one() - s;
+    let (f_plus, f_minus) = periodic_zeta(t, theta);
+    let (plus, minus) = reflection(t);
+    plus * f_minus + minus * f_plus
+}
+
+/// Returns how many terms of `Σ n^-t` are needed for the precision of `T`, if
+/// that's at most `PERIODIC_DIRECT_MAX`.
+fn periodic_direct_terms<T: Float>(t_re: T) -> Option<T> {
+    let n = (T::epsilon().ln() / (T::one() - t_re)).exp().ceil();
+    if n <= constant(PERIODIC_DIRECT_MAX) {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Returns `F(θ, t)` and `F(-θ, t)`, for `Re(t) > 1` and `|θ| ≤ 1/2`.
+///
+/// For large `Re(t)`, these are summed directly.  Otherwise they are the
+/// polylogarithms `Li_t(e^μ)` and `Li_t(e^-μ)`, with `μ = 2πiθ`,
+/// and `Li_t(e^μ) = Γ(1 - t) (-μ)^(t-1) + Σ ζ(t - k) μ^k / k!`.  The first
+/// term and the one with `k = n`, where `t - n` is nearest to 1, have poles
+/// that cancel, so they are taken together as `μ^n / n! Q(t - n - 1)`, with
+///
+/// `Q(ε) = ζ(1 + ε) - π (-μ)^ε n! / (sin(πε) Γ(n + 1 + ε))`.
+fn periodic_zeta<T: Float + FloatConst>(t: Complex<T>, theta: T) -> (Complex<T>, Complex<T>) {
+    if let Some(terms) = periodic_direct_terms(t.re) {
+        let two = T::one() + T::one();
+        let (mut plus, mut minus) = (Complex::zero(), Complex::zero());
+        let mut n = terms;
+        while n >= T::one() {
+            let power = pow_neg(n, t);
+            plus = plus + power * Complex::cis_pi(two * n * theta);
+            minus = minus + power * Complex::cis_pi(-two * n * theta);
+            n = n - T::one();
+        }
+        return (plus, minus);
+    }
+    let one = T::one();
+    let pi = T::PI();
+    let mu = Complex::new(T::zero(), (pi + pi) * theta);
+    let (ln_mu, ln_neg_mu) = (mu.ln(), (-mu).ln());
+    let n = t.re.round() - one;
+    let ln_factorial = Complex::new(n + one, T::zero()).ln_gamma();
+    let q = |e: Complex<T>| {
+        // π (∓μ)^ε n! / (sin(πε) Γ(n + 1 + ε)), in logarithms to avoid overflow
+        let ln_c = (Complex::new(n + one, T::zero()) + e).ln_gamma() - ln_factorial + ln_sin_pi(e);
+        let ln_c = Complex::new(pi.ln(), T::zero()) - ln_c;
+        let z = (e + one).zeta();
+        (
+            z - (ln_c + e * ln_neg_mu).exp(),
+            z - (ln_c + e * ln_mu).exp(),
+        )
+    };
+    let eps = t - (n + one);
+    let (q_plus, q_minus) = if eps.norm() >= constant(CAUCHY_NEAR) {
+        q(eps)
+    } else {
+        // Q(ε) = (1/2πi) ∮ Q(ζ) / (ζ - ε) dζ, around a circle of radius r
+        let points = constant::<T>(f64::from(CAUCHY_POINTS));
+        let radius = constant::<T>(CAUCHY_RADIUS);
+        let mut sum = (Complex::zero(), Complex::zero());
+        for j in 0..CAUCHY_POINTS {
+            let node =
+                Complex::cis_pi(constant::<T>(2.0 * f64::from(j) + 1.0) / points).scale(radius);
+            let weight = node / (node - eps);
+            let (p, m) = q(node);
+            sum = (sum.0 + p * weight, sum.1 + m * weight);
+        }
+        (sum.0.unscale(points), sum.1.unscale(points))
+    };
+
+    let (mut even, mut odd) = (Complex::<T>::zero(), Complex::<T>::zero());
+    let mut power = Complex::one();
+    let mut singular = Complex::zero();
+    let mut k = T::zero();
+    for _ in 0..PERIODIC_TERMS {
+        if k == n {
+            singular = power;
+        } else {
+            let term = (t - k).zeta() * power;
+            if k > n && term.norm() <= T::epsilon() * (even.norm() + odd.norm()) {
+                break;
+            }
+            if k % (one + one) == T::zero() {
+                even = even + term;
+            } else {
+                odd = odd + term;
+            }
+        }
+        k = k + one;
+        power = power * mu.unscale(k);
+    }
+    // (-μ)^n = ±μ^n
+    let sign = if n % (one + one) == T::zero() {
+        one
+    } else {
+        -one
+    };
+    (
+        even + odd + singular * q_plus,
+        even - odd + singular.scale(sign) * q_minus,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::float::close_to_tol;
+    use crate::Complex64;
+    use std::f64;
+
+    // (s, ζ(s)) with references from mpmath
+    type Case = ((f64, f64), (f64, f64));
+
+    // (s, a, ζ(s, a)) with references from mpmath
+    type HurwitzCase = ((f64, f64), f64, (f64, f64));
+
+    #[test]
+    fn test_zeta() {
+        let cases: [Case; 15] = [
+            ((3.0, 0.0), (1.2020569031595942, 0.0)),
+            ((0.5, 0.0), (-1.4603545088095868, 0.0)),
+            ((0.0, 0.0), (-0.5, 0.0)),
+            ((-0.5, 0.0), (-0.20788622497735457, 0.0)),
+            ((-1.5, 0.0), (-0.025485201889833036, 0.0)),
+            ((-13.0, 0.0), (-0.08333333333333333, 0.0)),
+            ((-25.5, 0.0), (-78486.1485692177, 0.0)),
+            ((60.0, 0.0), (1.0, 0.0)),
+            (
+                (0.001, 0.001),
+                (-0.5009189365276308, -0.0009209468912269623),
+            ),
+            ((2.0, 1.0), (1.1503557032549028, -0.4375308659196079)),
+            ((1.1, -0.2), (2.584646299688631, 3.985630183592174)),
+            ((4.0, -7.0), (1.0080663561626324, -0.051861117195166846)),
+            ((-3.0, 4.0), (-0.03373057338827757, 0.2774499251557094)),
+            ((-7.5, 0.25), (0.0035829266514338493, 0.001098620267953492)),
+            ((-20.0, 30.0), (-335758869224759.6, 50514282112309.51)),
+        ];
+        for &((x, y), (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 1e-13 * expected.norm();
+            assert!(close_to_tol(Complex64::new(x, y).zeta(), expected, tol));
+        }
+
+        // the trivial zeros and the Bernoulli numbers, ζ(-n) = -B_(n+1)/(n+1)
+        for n in 1..20 {
+            let z = Complex64::new(-2.0 * f64::from(n), 0.0).zeta();
+            assert_eq!(z, Complex64::new(0.0, 0.0));
+        }
+        let z = Complex64::new(-1.0, 0.0).zeta();
+        assert!(close_to_tol(z, Complex64::new(-1.0 / 12.0, 0.0), 1e-16));
+        let z = Complex64::new(-7.0, 0.0).zeta();
+        assert!(close_to_tol(z, Complex64::new(1.0 / 240.0, 0.0), 1e-17));
+
+        // ζ(conj(s)) = conj(ζ(s))
+        let s = Complex64::new(-3.5, 12.0);
+        assert_eq!(s.conj().zeta(), s.zeta().conj());
+    }
+
+    #[test]
+    fn test_zeta_critical_line() {
+        // The first zeros on the critical line, from A. M. Odlyzko's tables.
+        let zeros = [
+            14.134725141734693,
+            21.022039638771556,
+            25.01085758014569,
+            30.424876125859512,
+            32.93506158773919,
+            37.58617815882567,
+            236.5242296658162,
+        ];
+        for &t in zeros.iter() {
+            let z = Complex64::new(0.5, t).zeta();
+            // ζ'(1/2 + it) is about ln t, so only rounding t is visible
+            assert!(z.norm() < 2e-14 * t);
+        }
+
+        // and away from the zeros, with references from mpmath
+        let cases: [Case; 3] = [
+            ((0.5, 100.0), (2.692619885681324, -0.020386029602598162)),
+            ((0.5, 1000.0), (0.35633436719439604, 0.9319978312329936)),
+            ((0.5, 10000.0), (-0.33937380263883443, -0.03709150597320603)),
+        ];
+        for &((x, y), (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 1e-14 * y * expected.norm();
+            assert!(close_to_tol(Complex64::new(x, y).zeta(), expected, tol));
+        }
+    }
+
+    #[test]
+    fn test_zeta_special() {
+        let z = Complex64::new(1.0, 0.0).zeta();
+        assert!(z.re == f64::INFINITY && z.im.is_nan());
+        assert_eq!(
+            Complex64::new(f64::INFINITY, 1.0).zeta(),
+            Complex64::new(1.0, 0.0)
+        );
+        assert!(Complex64::new(f64::NEG_INFINITY, 0.0).zeta().is_nan());
+        assert!(Complex64::new(f64::NAN, 0.0).zeta().is_nan());
+        assert!(Complex64::new(1.0, 0.0).hurwitz_zeta(0.5).re == f64::INFINITY);
+        assert!(Complex64::new(2.0, 0.0).hurwitz_zeta(f64::NAN).is_nan());
+        // the term with n + a = 0
+        let z = Complex64::new(2.0, 0.0).hurwitz_zeta(-3.0);
+        assert!(z.re == f64::INFINITY && z.im.is_nan());
+        // too many negative terms to sum, on either side of Re(s) = 0
+        assert!(Complex64::new(-0.5, 1.0).hurwitz_zeta(-1e20).is_nan());
+        assert!(Complex64::new(2.0, 1.0).hurwitz_zeta(-1e9).is_nan());
+        assert!(Complex64::new(2.0, 0.0).hurwitz_zeta(-1e20).re == f64::INFINITY);
+    }
+
+    #[test]
+    fn test_hurwitz_zeta() {
+        let cases: [HurwitzCase; 11] = [
+            ((2.0, 0.0), 0.5, (4.934802200544679, 0.0)),
+            ((3.0, 1.0), 0.25, (12.305047903217496, 62.679383260605206)),
+            ((0.5, 2.0), 3.7, (-0.47629953733594643, 0.743197818839469)),
+            ((0.5, 0.0), 0.001, (30.16111640790577, 0.0)),
+            ((1.5, 40.0), 0.2, (0.7309912743625476, 10.337552640958211)),
+            (
+                (2.0, -3.0),
+                100.0,
+                (-0.00251390400913277, 0.0019446430989432657),
+            ),
+            ((4.0, 0.0), -2.5, (32.4579793698646, 0.0)),
+            ((-2.5, 0.0), 0.3, (-0.00949638093151452, 0.0)),
+            (
+                (-3.0, 2.0),
+                0.75,
+                (-0.04296417640531581, 0.023490006482820822),
+            ),
+            (
+                (-1.5, -10.0),
+                0.6,
+                (-1.6084201004761258, -1.0435242137777028),
+            ),
+            ((-10.5, 5.0), 0.1, (-2.2202673535276376, 6.381692086379939)),
+        ];
+        for &((x, y), a, (u, v)) in cases.iter() {
+            let expected = Complex64::new(u, v);
+            let tol = 1e-13 * expected.norm();
+            let z = Complex64::new(x, y).hurwitz_zeta(a);
+            assert!(close_to_tol(z, expected, tol));
+        }
+
+        // ζ(s, 1/2) = (2^s - 1) ζ(s), on both sides of the critical strip
+        for &(x, y) in [(-12.5, 3.0), (-2.0, -1.0), (1.5, 25.0), (7.0, 0.5)].iter() {
+            let s = Complex64::new(x, y);
+            let expected = (s.expf(2.0) - 1.0) * s.zeta();
+            let tol = 1e-13 * expected.norm();
+            assert!(close_to_tol(s.hurwitz_zeta(0.5), expected, tol));
+        }
+
+        // ζ(-n, a) = -B_(n+1)(a)/(n+1), through the removable singularity
+        let a: f64 = 0.25;
+        let bernoulli = [
+            a * a - a + 1.0 / 6.0,
+            a * a * a - 1.5 * a * a + 0.5 * a,
+            a.powi(4) - 2.0 * a.powi(3) + a * a - 1.0 / 30.0,
+            a.powi(5) - 2.5 * a.powi(4) + 5.0 / 3.0 * a.powi(3) - a / 6.0,
+        ];
+        for (n, &b) in bernoulli.iter().enumerate() {
+            let n = n as f64 + 1.0;
+            let z = Complex64::new(-n, 0.0).hurwitz_zeta(a);
+            let expected = Complex64::new(-b / (n + 1.0), 0.0);
+            assert!(close_to_tol(z, expected, 1e-15));
+        }
+
+        // ζ(s, a) = ζ(s, a + 1) + a^-s, also for a < 0
+        let s = Complex64::new(-4.5, 2.0);
+        for &a in [-999_999.7, -2.3, 0.3, 1.7, 42.5].iter() {
+            let z = s.hurwitz_zeta(a);
+            let expected = s.hurwitz_zeta(a + 1.0) + Complex64::new(a, 0.0).powc(-s);
+            assert!(close_to_tol(z, expected, 1e-13 * z.norm()));
+        }
+    }
+}